@@ -0,0 +1,163 @@
+//! Compares `ints` with the ways the days extract integers by hand.
+//!
+//! `cargo bench --bench ints`
+
+use adv_code_2024::bench::Stats;
+use adv_code_2024::ints::{ints, ints_per_line};
+use adv_code_2024::SolutionResult;
+use regex::Regex;
+use std::hint::black_box;
+use std::time::Instant;
+
+const WARMUP: usize = 5;
+const RUNS: usize = 50;
+
+/// Pseudo-random numbers below `limit`, the same on every run.
+fn numbers(count: usize, limit: u64) -> Vec<u64> {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    (0..count)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 33) % limit
+        })
+        .collect()
+}
+
+fn bench(name: &str, input: &str, mut f: impl FnMut(&str) -> i64) -> i64 {
+    let result = f(input);
+    for _ in 0..WARMUP {
+        black_box(f(black_box(input)));
+    }
+    let samples: Vec<_> = (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f(black_box(input)));
+            start.elapsed()
+        })
+        .collect();
+    let stats = Stats::from_samples(&samples);
+    println!(
+        "{:<36} min {:>10.2?}   median {:>10.2?}   p95 {:>10.2?}",
+        name, stats.min, stats.median, stats.p95
+    );
+    result
+}
+
+fn main() {
+    // Location ID pairs, like day 01
+    let pairs: String = numbers(20_000, 100_000)
+        .chunks(2)
+        .map(|pair| format!("{}   {}\n", pair[0], pair[1]))
+        .collect();
+    println!("--- {} lines of two IDs ---", pairs.lines().count());
+    let expected = bench("split_whitespace + parse", &pairs, |input| {
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+        for line in input.lines() {
+            let mut iter = line.split_whitespace();
+            left.push(iter.next().unwrap().parse::<i64>().unwrap());
+            right.push(iter.next().unwrap().parse::<i64>().unwrap());
+        }
+        left.iter().sum::<i64>() - right.iter().sum::<i64>()
+    });
+    let result = bench("ints_per_line::<i64, 2>", &pairs, |input| {
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+        for pair in ints_per_line::<i64, 2>(input) {
+            let [l, r] = pair.unwrap();
+            left.push(l);
+            right.push(r);
+        }
+        left.iter().sum::<i64>() - right.iter().sum::<i64>()
+    });
+    assert_eq!(result, expected);
+
+    // Comma separated updates, like day 05
+    let updates: String = numbers(20_000, 100)
+        .chunks(20)
+        .map(|update| {
+            let update: Vec<String> = update.iter().map(u64::to_string).collect();
+            update.join(",") + "\n"
+        })
+        .collect();
+    println!(
+        "--- {} lines of 20 comma separated pages ---",
+        updates.lines().count()
+    );
+    let expected = bench("split(',') + parse into Vec", &updates, |input| {
+        input
+            .lines()
+            .map(|line| {
+                let update: Vec<i64> = line.split(',').map(|n| n.parse().unwrap()).collect();
+                update[update.len() / 2]
+            })
+            .sum()
+    });
+    let result = bench("ints into Vec", &updates, |input| {
+        input
+            .lines()
+            .map(|line| {
+                let update: Vec<i64> = ints(line).collect::<SolutionResult<_>>().unwrap();
+                update[update.len() / 2]
+            })
+            .sum()
+    });
+    assert_eq!(result, expected);
+    let result = bench("ints summed", &updates, |input| {
+        ints::<i64>(input).map(Result::unwrap).sum()
+    });
+    assert_eq!(
+        result,
+        updates
+            .split(['\n', ','])
+            .flat_map(str::parse::<i64>)
+            .sum::<i64>()
+    );
+
+    // Claw machines, like day 13
+    let machines: String = numbers(6_000, 20_000)
+        .chunks(6)
+        .map(|m| {
+            format!(
+                "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n\n",
+                m[0] % 100,
+                m[1] % 100,
+                m[2] % 100,
+                m[3] % 100,
+                m[4],
+                m[5]
+            )
+        })
+        .collect();
+    println!(
+        "--- {} claw machines ---",
+        machines.split("\n\n").count() - 1
+    );
+    let expected = bench("Regex::captures", &machines, |input| {
+        let regex = Regex::new(r"X[+=](\d+), Y[+=](\d+)").unwrap();
+        regex
+            .captures_iter(input)
+            .map(|caps| caps[1].parse::<i64>().unwrap() * caps[2].parse::<i64>().unwrap())
+            .sum()
+    });
+    let result = bench("split_once + parse", &machines, |input| {
+        input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let (_, values) = line.split_once(": X").unwrap();
+                let (x, y) = values.split_once(", Y").unwrap();
+                x[1..].parse::<i64>().unwrap() * y[1..].parse::<i64>().unwrap()
+            })
+            .sum()
+    });
+    assert_eq!(result, expected);
+    let result = bench("ints_per_line::<i64, 2>", &machines, |input| {
+        ints_per_line::<i64, 2>(input)
+            .map(|xy| xy.map(|[x, y]| x * y).unwrap())
+            .sum()
+    });
+    assert_eq!(result, expected);
+}
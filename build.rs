@@ -0,0 +1,144 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Collects every `src/days/dayNN.rs` (or `dayYYYY_NN.rs` for other events) module into
+/// `$OUT_DIR/days.rs`, which is included by `src/days/mod.rs`. Each module registers itself
+/// with `register_solution!`.
+///
+/// Also writes `$OUT_DIR/fixtures.rs` with one test per example and part in `fixtures/`,
+/// included by the tests of `src/examples.rs`.
+fn main() {
+    println!("cargo:rerun-if-changed=src/days");
+    println!("cargo:rerun-if-changed=fixtures");
+
+    let mut modules: Vec<String> = fs::read_dir("src/days")
+        .expect("src/days is missing")
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            let module = name.strip_suffix(".rs")?;
+            let number = module.strip_prefix("day")?;
+            let valid = number.starts_with(|c: char| c.is_ascii_digit())
+                && number.chars().all(|c| c.is_ascii_digit() || c == '_');
+            if !valid {
+                return None;
+            }
+            Some(module.to_string())
+        })
+        .collect();
+    modules.sort();
+
+    let days_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src/days");
+    let mut generated = String::new();
+    for module in &modules {
+        let path = days_dir.join(format!("{}.rs", module));
+        generated += &format!("#[path = {:?}]\npub mod {};\n", path, module);
+    }
+
+    generated += "\n/// Every solution found in `src/days`, with the puzzle it registered for.\n";
+    generated +=
+        "pub fn registry() -> Vec<(crate::PuzzleId, Box<dyn crate::Solution>)> {\n    vec![\n";
+    for module in &modules {
+        generated += &format!("        ({0}::PUZZLE, {0}::solution()),\n", module);
+    }
+    generated += "    ]\n}\n\n";
+
+    generated += "const _: () = assert_unique_days(&[\n";
+    for module in &modules {
+        generated += &format!("    {}::PUZZLE,\n", module);
+    }
+    generated += "]);\n";
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("days.rs");
+    fs::write(out, generated).expect("Failed to write the days registry");
+
+    let fixtures = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("fixtures");
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("fixtures.rs");
+    fs::write(out, fixture_tests(&fixtures)).expect("Failed to write the fixture tests");
+}
+
+/// Test functions for `fixtures/NN` (default event) and `fixtures/YYYY/NN` directories.
+fn fixture_tests(root: &Path) -> String {
+    let mut dirs: Vec<(Option<u32>, u32, PathBuf)> = Vec::new();
+    for (name, path) in numbered_dirs(root) {
+        match name.len() {
+            2 => dirs.push((None, name.parse().unwrap(), path)),
+            4 => {
+                for (day, path) in numbered_dirs(&path).filter(|(day, _)| day.len() == 2) {
+                    dirs.push((Some(name.parse().unwrap()), day.parse().unwrap(), path));
+                }
+            }
+            _ => {}
+        }
+    }
+    dirs.sort();
+
+    let mut generated = String::new();
+    for (year, day, dir) in dirs {
+        let path = dir.join("answers.toml");
+        let answers: toml::Table = match fs::read_to_string(&path) {
+            Ok(text) => text
+                .parse()
+                .unwrap_or_else(|err| panic!("Invalid {}: {}", path.display(), err)),
+            Err(_) => toml::Table::new(),
+        };
+        let mut examples: Vec<String> = fs::read_dir(&dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter_map(|entry| {
+                let name = entry.file_name().into_string().ok()?;
+                let example = name.strip_suffix(".txt")?;
+                example.starts_with("example-").then(|| example.to_string())
+            })
+            .collect();
+        examples.sort();
+
+        for example in examples {
+            for part in listed_parts(&answers, &example) {
+                let prefix = match year {
+                    Some(year) => format!("y{}_", year),
+                    None => String::new(),
+                };
+                let test = format!(
+                    "{}day{:0>2}_part{}_{}",
+                    prefix,
+                    day,
+                    part,
+                    example.replace(|c: char| !c.is_ascii_alphanumeric(), "_")
+                );
+                let year = match year {
+                    Some(year) => year.to_string(),
+                    None => "crate::puzzle::YEAR".to_string(),
+                };
+                generated += &format!(
+                    "#[test]\nfn {}() {{\n    check_fixture({}, {}, {}, {:?});\n}}\n\n",
+                    test, year, day, part, example
+                );
+            }
+        }
+    }
+    generated
+}
+
+fn numbered_dirs(dir: &Path) -> impl Iterator<Item = (String, PathBuf)> {
+    fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| Some((entry.file_name().into_string().ok()?, entry.path())))
+        .filter(|(name, _)| name.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Parts with an answer for `example` in the `[example-N]` tables of an `answers.toml`.
+fn listed_parts(answers: &toml::Table, example: &str) -> Vec<u8> {
+    let Some(table) = answers.get(example).and_then(toml::Value::as_table) else {
+        return Vec::new();
+    };
+    [1, 2]
+        .into_iter()
+        .filter(|part| table.contains_key(&format!("part{}", part)))
+        .collect()
+}
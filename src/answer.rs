@@ -0,0 +1,213 @@
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// Result of a solved puzzle part.
+///
+/// Integer answers compare by value regardless of their width, so `Answer::U64(5)` equals
+/// `Answer::I128(5)`. Text answers compare equal to integers with the same decimal
+/// representation, which keeps answers read back from text files comparable.
+#[derive(Debug, Clone, Default)]
+pub enum Answer {
+    U64(u64),
+    I64(i64),
+    U128(u128),
+    I128(i128),
+    Text(String),
+    #[default]
+    Unsolved,
+}
+
+impl Answer {
+    pub fn is_solved(&self) -> bool {
+        !matches!(self, Answer::Unsolved)
+    }
+
+    /// The answer as a TOML file can hold it. TOML integers are 64-bit signed, wider
+    /// answers are stored as text, which still compares equal to the integer.
+    pub(crate) fn to_toml(&self) -> Answer {
+        match self {
+            Answer::U64(value) if i64::try_from(*value).is_err() => self.to_string().into(),
+            Answer::U128(_) | Answer::I128(_) => self.to_string().into(),
+            _ => self.clone(),
+        }
+    }
+
+    /// Sign and magnitude of an integer answer.
+    fn integer(&self) -> Option<(bool, u128)> {
+        match *self {
+            Answer::U64(value) => Some((false, value as u128)),
+            Answer::I64(value) => Some((value < 0, value.unsigned_abs() as u128)),
+            Answer::U128(value) => Some((false, value)),
+            Answer::I128(value) => Some((value < 0, value.unsigned_abs())),
+            Answer::Text(_) | Answer::Unsolved => None,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::U64(value) => write!(f, "{}", value),
+            Answer::I64(value) => write!(f, "{}", value),
+            Answer::U128(value) => write!(f, "{}", value),
+            Answer::I128(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+            Answer::Unsolved => write!(f, "Not solved yet"),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Unsolved, Answer::Unsolved) => true,
+            (Answer::Unsolved, _) | (_, Answer::Unsolved) => false,
+            (Answer::Text(left), Answer::Text(right)) => left == right,
+            (Answer::Text(text), number) | (number, Answer::Text(text)) => {
+                *text == number.to_string()
+            }
+            (left, right) => left.integer() == right.integer(),
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+macro_rules! impl_integer {
+    ($($ty:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(value: $ty) -> Self {
+                    Answer::$variant(value as _)
+                }
+            }
+
+            impl PartialEq<$ty> for Answer {
+                fn eq(&self, other: &$ty) -> bool {
+                    let other = Answer::from(*other);
+                    *self == other
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(
+    u8 => U64, u16 => U64, u32 => U64, u64 => U64, usize => U64,
+    i8 => I64, i16 => I64, i32 => I64, i64 => I64, isize => I64,
+    u128 => U128, i128 => I128,
+);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        match self {
+            Answer::Unsolved => false,
+            Answer::Text(text) => text == other,
+            number => {
+                let text = number.to_string();
+                text == other
+            }
+        }
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::U64(value) => serializer.serialize_u64(*value),
+            Answer::I64(value) => serializer.serialize_i64(*value),
+            Answer::U128(value) => serializer.serialize_u128(*value),
+            Answer::I128(value) => serializer.serialize_i128(*value),
+            Answer::Text(value) => serializer.serialize_str(value),
+            Answer::Unsolved => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(AnswerVisitor)
+    }
+}
+
+struct AnswerVisitor;
+
+impl<'de> Visitor<'de> for AnswerVisitor {
+    type Value = Answer;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an integer, a string or nothing")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Answer, E> {
+        Ok(Answer::U64(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Answer, E> {
+        Ok(match u64::try_from(value) {
+            Ok(value) => Answer::U64(value),
+            Err(_) => Answer::I64(value),
+        })
+    }
+
+    fn visit_u128<E: de::Error>(self, value: u128) -> Result<Answer, E> {
+        Ok(Answer::U128(value))
+    }
+
+    fn visit_i128<E: de::Error>(self, value: i128) -> Result<Answer, E> {
+        Ok(Answer::I128(value))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Answer, E> {
+        Ok(Answer::Text(value.to_string()))
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Answer, E> {
+        Ok(Answer::Unsolved)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Answer, E> {
+        Ok(Answer::Unsolved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numeric_equality() {
+        assert_eq!(Answer::U64(42), Answer::I128(42));
+        assert_eq!(Answer::from(-7_i32), -7_i64);
+        assert_ne!(Answer::U128(u128::MAX), Answer::U64(u64::MAX));
+        assert_eq!(Answer::from(3749_u64), "3749");
+        assert_eq!(Answer::from("4,6,3,5"), Answer::Text("4,6,3,5".to_string()));
+        assert_ne!(Answer::Unsolved, Answer::from("Not solved yet"));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::U128(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(Answer::I64(-3).to_string(), "-3");
+        assert_eq!(Answer::Unsolved.to_string(), "Not solved yet");
+    }
+}
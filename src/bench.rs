@@ -0,0 +1,230 @@
+use crate::{Day, PuzzleId, SolutionResult};
+use std::collections::BTreeMap;
+use std::hint::black_box;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Default location of the benchmark baseline, relative to the crate root.
+pub const BASELINE_FILE: &str = ".bench-baseline.toml";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BenchConfig {
+    pub warmup: usize,
+    pub runs: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            warmup: 3,
+            runs: 20,
+        }
+    }
+}
+
+/// Summary of a set of timing samples.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len().max(1);
+        let percentile = |p: usize| sorted.get((sorted.len() * p).div_ceil(100).saturating_sub(1));
+
+        let nanos: Vec<f64> = sorted.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n as f64;
+
+        Stats {
+            runs: sorted.len(),
+            min: sorted.first().copied().unwrap_or_default(),
+            median: percentile(50).copied().unwrap_or_default(),
+            p95: percentile(95).copied().unwrap_or_default(),
+            mean: Duration::from_nanos(mean as u64),
+            stddev: Duration::from_nanos(variance.sqrt() as u64),
+        }
+    }
+}
+
+/// Timings of a single puzzle part.
+///
+/// Parse time comes from `Solution::parse`, solve time is the time of the whole part with
+/// the median parse time taken off. Solutions without a separate parse step have no parse
+/// time, their solve time is the whole part.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PartBench {
+    pub id: PuzzleId,
+    pub part: u8,
+    pub parse: Option<Stats>,
+    pub solve: Stats,
+}
+
+impl PartBench {
+    pub fn total(&self) -> Duration {
+        self.parse.map_or(Duration::ZERO, |parse| parse.median) + self.solve.median
+    }
+}
+
+/// Runs a part `config.runs` times after `config.warmup` untimed runs.
+pub fn bench_part(day: &Day, part: u8, config: BenchConfig) -> SolutionResult<PartBench> {
+    let solution = day
+        .solution
+        .as_ref()
+        .ok_or_else(|| crate::SolutionError::new("Not solved yet").for_puzzle(day.id, part))?;
+    let input = day.input().map_err(|err| err.for_puzzle(day.id, part))?;
+
+    for _ in 0..config.warmup {
+        black_box(day.solve_part(part)?);
+    }
+
+    let mut parse = Vec::with_capacity(config.runs);
+    let mut total = Vec::with_capacity(config.runs);
+    for _ in 0..config.runs {
+        let start = Instant::now();
+        if let Some(parsed) = black_box(solution.parse(black_box(input))) {
+            parsed.map_err(|err| err.for_puzzle(day.id, part))?;
+            parse.push(start.elapsed());
+        }
+
+        let start = Instant::now();
+        black_box(day.solve_part(part)?);
+        total.push(start.elapsed());
+    }
+
+    let parse = (!parse.is_empty()).then(|| Stats::from_samples(&parse));
+    let parse_median = parse.map_or(Duration::ZERO, |parse| parse.median);
+    let solve: Vec<Duration> = total
+        .iter()
+        .map(|d| d.saturating_sub(parse_median))
+        .collect();
+    Ok(PartBench {
+        id: day.id,
+        part,
+        parse,
+        solve: Stats::from_samples(&solve),
+    })
+}
+
+/// Median total time per puzzle part of an earlier run, stored as
+///
+/// ```toml
+/// [2024.01]
+/// part1 = 1520300 # nanoseconds
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Baseline {
+    medians: BTreeMap<(PuzzleId, u8), Duration>,
+}
+
+type BaselineFile = BTreeMap<String, BTreeMap<String, BTreeMap<String, u64>>>;
+
+impl Baseline {
+    /// Reads the baseline at `path`, a missing file is an empty baseline.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Baseline::default()),
+            Err(err) => return Err(err),
+        };
+        let file: BaselineFile = toml::from_str(&text).map_err(io::Error::other)?;
+
+        let mut baseline = Baseline::default();
+        for (year, days) in file {
+            for (day, parts) in days {
+                for (part, nanos) in parts {
+                    let id = year
+                        .parse()
+                        .ok()
+                        .zip(day.parse().ok())
+                        .and_then(|(year, day)| PuzzleId::new(year, day).ok());
+                    let part = part.strip_prefix("part").and_then(|p| p.parse().ok());
+                    if let (Some(id), Some(part)) = (id, part) {
+                        baseline.insert(id, part, Duration::from_nanos(nanos));
+                    }
+                }
+            }
+        }
+        Ok(baseline)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut file = BaselineFile::new();
+        for ((id, part), median) in &self.medians {
+            file.entry(id.year.to_string())
+                .or_default()
+                .entry(format!("{:0>2}", id.day))
+                .or_default()
+                .insert(format!("part{}", part), median.as_nanos() as u64);
+        }
+        std::fs::write(path, toml::to_string(&file).map_err(io::Error::other)?)
+    }
+
+    pub fn get(&self, id: PuzzleId, part: u8) -> Option<Duration> {
+        self.medians.get(&(id, part)).copied()
+    }
+
+    pub fn insert(&mut self, id: PuzzleId, part: u8, median: Duration) {
+        self.medians.insert((id, part), median);
+    }
+
+    /// Relative change of `bench` against the baseline, `0.25` means 25% slower.
+    pub fn change(&self, bench: &PartBench) -> Option<f64> {
+        let baseline = self.get(bench.id, bench.part)?.as_nanos() as f64;
+        (baseline > 0.0).then(|| bench.total().as_nanos() as f64 / baseline - 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples: Vec<Duration> = [5, 1, 4, 2, 3].map(Duration::from_millis).to_vec();
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(stats.runs, 5);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.p95, Duration::from_millis(5));
+        assert_eq!(stats.mean, Duration::from_millis(3));
+        assert_eq!(stats.stddev.as_micros(), 1414);
+    }
+
+    #[test]
+    fn test_baseline() {
+        let id = PuzzleId::day(1).unwrap();
+        let mut baseline = Baseline::default();
+        baseline.insert(id, 1, Duration::from_micros(100));
+
+        let path = std::env::temp_dir().join(format!("aoc-bench-{}.toml", std::process::id()));
+        baseline.save(&path).unwrap();
+        let baseline = Baseline::load(&path).unwrap();
+        std::fs::remove_file(path).unwrap();
+
+        let stats = Stats::from_samples(&[Duration::from_micros(60)]);
+        let bench = PartBench {
+            id,
+            part: 1,
+            parse: Some(stats),
+            solve: stats,
+        };
+        let change = baseline.change(&bench).unwrap();
+        assert!((change - 0.2).abs() < 1e-9);
+        let unparsed = PartBench {
+            parse: None,
+            ..bench
+        };
+        assert_eq!(unparsed.total(), Duration::from_micros(60));
+        assert_eq!(baseline.change(&PartBench { part: 2, ..bench }), None);
+    }
+}
@@ -1,12 +1,9 @@
 use adv_code_2024::*;
 use anyhow::*;
 use code_timing_macros::time_snippet;
-use const_format::concatcp;
-use std::fs::File;
 use std::io::{BufRead, BufReader};
 
 const DAY: &str = "NN"; // TODO: Fill the day
-const INPUT_FILE: &str = concatcp!("input/", DAY, ".txt");
 
 const TEST: &str = "\
 <TEST-INPUT>
@@ -14,20 +11,25 @@ const TEST: &str = "\
 
 fn main() -> Result<()> {
     start_day(DAY);
+    let aoc = AdventOfCode::new();
+    let input = aoc
+        .day(PuzzleId::day(DAY.parse()?)?)
+        .context("Unknown day")?
+        .input()?;
 
     //region Part 1
     println!("=== Part 1 ===");
 
     fn part1<R: BufRead>(reader: R) -> Result<usize> {
         // TODO: Solve Part 1 of the puzzle
-        let answer = reader.lines().flatten().count();
+        let answer = reader.lines().map_while(Result::ok).count();
         Ok(answer)
     }
 
     // TODO: Set the expected answer for the test input
     assert_eq!(0, part1(BufReader::new(TEST.as_bytes()))?);
 
-    let input_file = BufReader::new(File::open(INPUT_FILE)?);
+    let input_file = BufReader::new(input.as_bytes());
     let result = time_snippet!(part1(input_file)?);
     println!("Result = {}", result);
     //endregion
@@ -41,7 +43,7 @@ fn main() -> Result<()> {
     //
     // assert_eq!(0, part2(BufReader::new(TEST.as_bytes()))?);
     //
-    // let input_file = BufReader::new(File::open(INPUT_FILE)?);
+    // let input_file = BufReader::new(input.as_bytes());
     // let result = time_snippet!(part2(input_file)?);
     // println!("Result = {}", result);
     //endregion
@@ -0,0 +1,200 @@
+use crate::{Answer, ErrorKind, PuzzleId, SolutionResult};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io;
+use std::path::Path;
+
+/// Default location of the answer registry, relative to the crate root.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Known-good answers per puzzle and part, stored as
+///
+/// ```toml
+/// [2024.01]
+/// part1 = 11
+/// part2 = 31
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ExpectedAnswers {
+    answers: BTreeMap<(PuzzleId, u8), Answer>,
+}
+
+/// Answers of both parts, as stored in TOML tables.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct Parts {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<Answer>,
+}
+
+type AnswersFile = BTreeMap<String, BTreeMap<String, Parts>>;
+
+impl ExpectedAnswers {
+    /// Reads the registry at `path`, a missing file is an empty registry.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => ExpectedAnswers::parse(&text)
+                .map_err(|err| io::Error::other(format!("{}: {}", path.display(), err))),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(ExpectedAnswers::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let file: AnswersFile = toml::from_str(text).map_err(|err| err.to_string())?;
+        let mut answers = ExpectedAnswers::default();
+        for (year, days) in file {
+            for (day, parts) in days {
+                let id = match (year.parse(), day.parse()) {
+                    (Ok(year), Ok(day)) => PuzzleId::new(year, day).map_err(|e| e.to_string())?,
+                    _ => return Err(format!("Invalid puzzle `{}.{}`", year, day)),
+                };
+                for (part, answer) in [(1, parts.part1), (2, parts.part2)] {
+                    if let Some(answer) = answer.filter(Answer::is_solved) {
+                        answers.insert(id, part, answer);
+                    }
+                }
+            }
+        }
+        Ok(answers)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut file = AnswersFile::new();
+        for ((id, part), answer) in &self.answers {
+            let parts = file
+                .entry(id.year.to_string())
+                .or_default()
+                .entry(format!("{:0>2}", id.day))
+                .or_default();
+            let answer = answer.to_toml();
+            match part {
+                1 => parts.part1 = Some(answer),
+                _ => parts.part2 = Some(answer),
+            }
+        }
+        let text = toml::to_string(&file).map_err(io::Error::other)?;
+        std::fs::write(path, text)
+    }
+
+    pub fn get(&self, id: PuzzleId, part: u8) -> Option<&Answer> {
+        self.answers.get(&(id, part))
+    }
+
+    pub fn insert(&mut self, id: PuzzleId, part: u8, answer: Answer) {
+        self.answers.insert((id, part), answer);
+    }
+
+    /// Compares a solved part against the registry.
+    pub fn check(&self, id: PuzzleId, part: u8, solution: &SolutionResult<Answer>) -> CheckStatus {
+        match (solution, self.get(id, part)) {
+            (Err(err), _) if err.kind == ErrorKind::TimedOut => {
+                CheckStatus::TimedOut(err.to_string())
+            }
+            (Err(err), _) => CheckStatus::Error(err.to_string()),
+            (Ok(Answer::Unsolved), _) => CheckStatus::Unsolved,
+            (Ok(_), None) => CheckStatus::New,
+            (Ok(answer), Some(expected)) if answer == expected => CheckStatus::Pass,
+            (Ok(_), Some(expected)) => CheckStatus::Fail(expected.clone()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckStatus {
+    Pass,
+    Fail(Answer),
+    New,
+    Unsolved,
+    Error(String),
+    TimedOut(String),
+}
+
+impl CheckStatus {
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            CheckStatus::Fail(_) | CheckStatus::Error(_) | CheckStatus::TimedOut(_)
+        )
+    }
+}
+
+impl fmt::Display for CheckStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CheckStatus::Pass => f.pad("pass"),
+            CheckStatus::Fail(_) => f.pad("FAIL"),
+            CheckStatus::New => f.pad("new"),
+            CheckStatus::Unsolved => f.pad("-"),
+            CheckStatus::Error(_) => f.pad("ERROR"),
+            CheckStatus::TimedOut(_) => f.pad("TIMEOUT"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SolutionError;
+
+    const ANSWERS: &str = "\
+[2024.01]
+part1 = 11
+part2 = \"31\"
+
+[2023.25]
+part1 = \"170141183460469231731687303715884105727\"
+";
+
+    #[test]
+    fn test_check() {
+        let answers = ExpectedAnswers::parse(ANSWERS).unwrap();
+        let day01 = PuzzleId::day(1).unwrap();
+        let day25 = PuzzleId::new(2023, 25).unwrap();
+
+        assert_eq!(
+            answers.check(day01, 1, &Ok(11_u64.into())),
+            CheckStatus::Pass
+        );
+        assert_eq!(
+            answers.check(day01, 2, &Ok(31_u64.into())),
+            CheckStatus::Pass
+        );
+        assert_eq!(
+            answers.check(day01, 1, &Ok(12_u64.into())),
+            CheckStatus::Fail(Answer::U64(11))
+        );
+        assert_eq!(answers.check(day25, 2, &Ok(1_u64.into())), CheckStatus::New);
+        assert_eq!(
+            answers.check(day25, 1, &Ok(Answer::U128(i128::MAX as u128))),
+            CheckStatus::Pass
+        );
+        assert!(answers
+            .check(day01, 1, &Err(SolutionError::new("bad input")))
+            .is_failure());
+        let timeout = SolutionError::timed_out(std::time::Duration::from_secs(1));
+        assert!(matches!(
+            answers.check(day01, 1, &Err(timeout)),
+            CheckStatus::TimedOut(_)
+        ));
+    }
+
+    #[test]
+    fn test_save_round_trip() {
+        let mut answers = ExpectedAnswers::default();
+        answers.insert(PuzzleId::day(7).unwrap(), 2, Answer::U64(u64::MAX));
+        answers.insert(PuzzleId::day(17).unwrap(), 1, "4,6,3,5".into());
+        answers.insert(PuzzleId::new(2023, 1).unwrap(), 1, Answer::I64(-5));
+
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}.toml", std::process::id()));
+        answers.save(&path).unwrap();
+        let loaded = ExpectedAnswers::load(&path).unwrap();
+        std::fs::remove_file(path).unwrap();
+
+        for ((id, part), answer) in &answers.answers {
+            assert_eq!(loaded.get(*id, *part), Some(answer));
+        }
+    }
+}
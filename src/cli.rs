@@ -0,0 +1,561 @@
+use crate::bench::BenchConfig;
+use crate::puzzle::{self, PuzzleId};
+use crate::report::Format;
+use crate::runner;
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+pub const USAGE: &str = "\
+Usage: adv-code-2024 [COMMAND] [OPTIONS]
+
+Commands:
+  run               Solve the selected days (default)
+  bench             Benchmark the selected days
+  new-day <N>       Create src/days/dayNN.rs and an empty input file for day N
+  fetch             Download the inputs of the selected days which are not there yet,
+                    using the session token in $AOC_SESSION or .aoc-session
+  submit            Solve a single `--day` and `--part` and submit the answer
+  repl              Explore the solutions interactively, starting on the `--day` given
+  status            Show the stars collected in progress.toml, with details for the `--day`s given
+  examples          Extract the examples of a single `--day` from its puzzle description
+                    (`--html <FILE>`, downloaded if not given) into fixtures/NN
+
+Options:
+  --day <N>         Run a single day (e.g. `--day 5`)
+  --day <A>..=<B>   Run a range of days (e.g. `--day 3..=7`)
+  --all             Run every registered day (default)
+  --year <YEAR>     Event to run (default: 2024)
+  --part <1|2>      Only run the given part
+  --input <PATH>    Read the puzzle input from PATH, `-` for stdin (single day only)
+  --input-dir <DIR> Read inputs from DIR instead of $AOC_INPUT_DIR, `input_dir` in
+                    aoc.toml or the crate's `input` directory
+  --input-archive <FILE>
+                    Read inputs from a .tar.gz archive laid out like the input directory
+  --check           Compare the answers against the answer registry
+  --record          With `--check`, add new answers to the registry
+  --answers <FILE>  Answer registry to use (default: answers.toml in the crate root)
+  --format <FORMAT> Report format: text (default), json, csv or markdown
+  --timeout <SECS>  Give up on parts running longer than SECS seconds
+  -j, --jobs <N>    Solve up to N parts in parallel (default: number of CPUs)
+  -w, --watch       Rebuild and re-run the selection and its examples whenever src/days, src/bin,
+                    the inputs or the fixtures change
+  --answer <ANSWER> With `submit`, submit ANSWER instead of solving the part
+  --html <FILE>     With `examples`, read the puzzle description from a saved page
+  -h, --help        Print this help
+
+Bench options:
+  --runs <N>        Timed runs per part (default: 20)
+  --warmup <N>      Untimed runs before timing (default: 3)
+  --baseline <FILE> Baseline to compare against (default: .bench-baseline.toml in the crate root)
+  --save-baseline   Store the medians of this run as the new baseline
+  --threshold <PCT> Slowdown against the baseline reported as regression (default: 10)
+";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Run,
+    Bench,
+    NewDay,
+    Fetch,
+    Submit,
+    Examples,
+    Status,
+    Repl,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchOptions {
+    pub config: BenchConfig,
+    pub baseline: Option<PathBuf>,
+    pub save_baseline: bool,
+    pub threshold: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DaySelection {
+    All,
+    Days(RangeInclusive<u32>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Options {
+    pub command: Command,
+    pub year: u32,
+    pub days: DaySelection,
+    pub part: Option<u8>,
+    pub input: Option<PathBuf>,
+    pub input_dir: Option<PathBuf>,
+    pub input_archive: Option<PathBuf>,
+    pub check: bool,
+    pub record: bool,
+    pub answers: Option<PathBuf>,
+    pub format: Format,
+    pub jobs: Option<usize>,
+    pub timeout: Option<Duration>,
+    pub answer: Option<String>,
+    pub html: Option<PathBuf>,
+    pub watch: bool,
+    pub bench: BenchOptions,
+    pub help: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliError {
+    Usage(String),
+    UnknownYear(u32),
+    UnknownDay(u32),
+    NotSolved(PuzzleId),
+    MissingInput(String),
+    Failed(usize),
+    Regressed(usize),
+    Rejected(String),
+}
+
+impl CliError {
+    /// Process exit code reported for this error.
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            CliError::Usage(_) => 2,
+            CliError::UnknownYear(_) | CliError::UnknownDay(_) => 3,
+            CliError::NotSolved(_) => 4,
+            CliError::MissingInput(_) => 5,
+            CliError::Failed(_) => 6,
+            CliError::Regressed(_) => 7,
+            CliError::Rejected(_) => 8,
+        }
+    }
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Usage(msg) => write!(f, "{}", msg),
+            CliError::UnknownYear(year) => write!(f, "Year {} is not in the calendar", year),
+            CliError::UnknownDay(day) => write!(f, "Day {} is not part of the calendar", day),
+            CliError::NotSolved(id) => write!(f, "{} has no registered solution", id),
+            CliError::MissingInput(msg) => write!(f, "{}", msg),
+            CliError::Failed(count) => write!(f, "{} part(s) failed", count),
+            CliError::Regressed(count) => {
+                write!(f, "{} part(s) got slower than the baseline", count)
+            }
+            CliError::Rejected(msg) => write!(f, "Not submitted: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            command: Command::Run,
+            year: puzzle::YEAR,
+            days: DaySelection::All,
+            part: None,
+            input: None,
+            input_dir: None,
+            input_archive: None,
+            check: false,
+            record: false,
+            answers: None,
+            format: Format::Text,
+            jobs: None,
+            timeout: None,
+            answer: None,
+            html: None,
+            watch: false,
+            bench: BenchOptions {
+                config: BenchConfig::default(),
+                baseline: None,
+                save_baseline: false,
+                threshold: 10.0,
+            },
+            help: false,
+        }
+    }
+}
+
+impl Options {
+    /// Parses the arguments following the program name.
+    pub fn parse<I, S>(args: I) -> Result<Self, CliError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut options = Options::default();
+        let mut args = args.into_iter().map(Into::into).peekable();
+        let mut day_given = false;
+
+        match args.peek().map(String::as_str) {
+            Some("run") => {
+                args.next();
+            }
+            Some("bench") => {
+                options.command = Command::Bench;
+                args.next();
+            }
+            Some("fetch") => {
+                options.command = Command::Fetch;
+                args.next();
+            }
+            Some("submit") => {
+                options.command = Command::Submit;
+                args.next();
+            }
+            Some("repl") => {
+                options.command = Command::Repl;
+                args.next();
+            }
+            Some("status") => {
+                options.command = Command::Status;
+                args.next();
+            }
+            Some("examples") => {
+                options.command = Command::Examples;
+                args.next();
+            }
+            Some("new-day") => {
+                options.command = Command::NewDay;
+                args.next();
+                if let Some(day) = args.next_if(|arg| !arg.starts_with('-')) {
+                    options.days = DaySelection::Days(parse_days(&day)?);
+                    day_given = true;
+                }
+            }
+            _ => {}
+        }
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--day" | "-d" => {
+                    options.days = DaySelection::Days(parse_days(&value(&arg, args.next())?)?);
+                    day_given = true;
+                }
+                "--all" | "-a" => options.days = DaySelection::All,
+                "--year" | "-y" => options.year = number(&arg, args.next())?,
+                "--part" | "-p" => {
+                    options.part = match value(&arg, args.next())?.as_str() {
+                        "1" => Some(1),
+                        "2" => Some(2),
+                        other => {
+                            return Err(CliError::Usage(format!("Invalid part `{}`", other)));
+                        }
+                    }
+                }
+                "--input" | "-i" => options.input = Some(PathBuf::from(value(&arg, args.next())?)),
+                "--input-dir" => options.input_dir = Some(PathBuf::from(value(&arg, args.next())?)),
+                "--input-archive" => {
+                    options.input_archive = Some(PathBuf::from(value(&arg, args.next())?))
+                }
+                "--check" => options.check = true,
+                "--watch" | "-w" => options.watch = true,
+                "--record" => options.record = true,
+                "--answers" => options.answers = Some(PathBuf::from(value(&arg, args.next())?)),
+                "--format" | "-f" => {
+                    options.format = value(&arg, args.next())?.parse().map_err(CliError::Usage)?
+                }
+                "--jobs" | "-j" => options.jobs = Some(number(&arg, args.next())?),
+                "--timeout" | "-t" => {
+                    let secs: f64 = number(&arg, args.next())?;
+                    match Duration::try_from_secs_f64(secs) {
+                        Ok(timeout) if !timeout.is_zero() => options.timeout = Some(timeout),
+                        _ => {
+                            return Err(CliError::Usage(format!(
+                                "`--timeout` has to be a positive number of seconds, not {}",
+                                secs
+                            )))
+                        }
+                    }
+                }
+                "--answer" => options.answer = Some(value(&arg, args.next())?),
+                "--html" => options.html = Some(PathBuf::from(value(&arg, args.next())?)),
+                "--runs" => options.bench.config.runs = number(&arg, args.next())?,
+                "--warmup" => options.bench.config.warmup = number(&arg, args.next())?,
+                "--baseline" => {
+                    options.bench.baseline = Some(PathBuf::from(value(&arg, args.next())?))
+                }
+                "--save-baseline" => options.bench.save_baseline = true,
+                "--threshold" => options.bench.threshold = number(&arg, args.next())?,
+                "--help" | "-h" => options.help = true,
+                other => return Err(CliError::Usage(format!("Unknown argument `{}`", other))),
+            }
+        }
+
+        if options.command == Command::NewDay {
+            match &options.days {
+                DaySelection::Days(days) if day_given && days.start() == days.end() => {}
+                _ => {
+                    return Err(CliError::Usage(
+                        "`new-day` requires a single day".to_string(),
+                    ))
+                }
+            }
+        }
+
+        if options.command == Command::Submit {
+            match &options.days {
+                DaySelection::Days(days)
+                    if day_given && days.start() == days.end() && options.part.is_some() => {}
+                _ => {
+                    return Err(CliError::Usage(
+                        "`submit` requires a single `--day` and a `--part`".to_string(),
+                    ))
+                }
+            }
+        } else if options.answer.is_some() {
+            return Err(CliError::Usage("`--answer` requires `submit`".to_string()));
+        }
+
+        if options.command == Command::Examples {
+            match &options.days {
+                DaySelection::Days(days) if day_given && days.start() == days.end() => {}
+                _ => {
+                    return Err(CliError::Usage(
+                        "`examples` requires a single `--day`".to_string(),
+                    ))
+                }
+            }
+        } else if options.html.is_some() {
+            return Err(CliError::Usage("`--html` requires `examples`".to_string()));
+        }
+
+        if options.watch
+            && (options.command != Command::Run
+                || options.check
+                || options.format != Format::Text
+                || options.input.as_deref() == Some(Path::new("-")))
+        {
+            return Err(CliError::Usage(
+                "`--watch` only supports solving without `--check`, `--format` or stdin"
+                    .to_string(),
+            ));
+        }
+
+        if options.jobs == Some(0) {
+            return Err(CliError::Usage("`--jobs` has to be at least 1".to_string()));
+        }
+
+        if options.bench.config.runs == 0 {
+            return Err(CliError::Usage("`--runs` has to be at least 1".to_string()));
+        }
+
+        if options.record && !options.check {
+            return Err(CliError::Usage("`--record` requires `--check`".to_string()));
+        }
+
+        if options.format != Format::Text && (options.check || options.command != Command::Run) {
+            return Err(CliError::Usage(
+                "`--format` is only supported when solving without `--check`".to_string(),
+            ));
+        }
+
+        if options.command == Command::Fetch
+            && (options.input.is_some() || options.input_archive.is_some())
+        {
+            return Err(CliError::Usage(
+                "`fetch` only supports an input directory".to_string(),
+            ));
+        }
+
+        if options.input_dir.is_some() && options.input_archive.is_some() {
+            return Err(CliError::Usage(
+                "`--input-dir` and `--input-archive` are mutually exclusive".to_string(),
+            ));
+        }
+
+        if options.input.is_some() {
+            match &options.days {
+                DaySelection::Days(days) if day_given && days.start() == days.end() => {}
+                _ => {
+                    return Err(CliError::Usage(
+                        "`--input` requires a single `--day`".to_string(),
+                    ))
+                }
+            }
+        }
+
+        Ok(options)
+    }
+
+    /// Parts selected by `--part`.
+    pub fn parts(&self) -> Vec<u8> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+
+    /// Worker threads selected by `--jobs`.
+    pub fn jobs(&self) -> usize {
+        self.jobs.unwrap_or_else(runner::default_jobs)
+    }
+
+    /// Puzzles selected by `--day`, or `None` when every registered day should run.
+    pub fn puzzles(&self) -> Result<Option<Vec<PuzzleId>>, CliError> {
+        match &self.days {
+            DaySelection::All => Ok(None),
+            DaySelection::Days(days) => days
+                .clone()
+                .map(|day| {
+                    PuzzleId::new(self.year, day).map_err(|err| match err {
+                        puzzle::CalendarError::InvalidYear(year) => CliError::UnknownYear(year),
+                        _ => CliError::UnknownDay(day),
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map(Some),
+        }
+    }
+}
+
+fn value(flag: &str, value: Option<String>) -> Result<String, CliError> {
+    value.ok_or_else(|| CliError::Usage(format!("Missing value for `{}`", flag)))
+}
+
+fn number<T: FromStr>(flag: &str, value: Option<String>) -> Result<T, CliError> {
+    let value = self::value(flag, value)?;
+    value
+        .parse()
+        .map_err(|_| CliError::Usage(format!("Invalid value `{}` for `{}`", value, flag)))
+}
+
+fn parse_days(value: &str) -> Result<RangeInclusive<u32>, CliError> {
+    let parse = |day: &str| {
+        day.trim()
+            .parse::<u32>()
+            .map_err(|_| CliError::Usage(format!("Invalid day `{}`", day)))
+    };
+
+    let days = match value.split_once("..=") {
+        Some((start, end)) => parse(start)?..=parse(end)?,
+        None => {
+            let day = parse(value)?;
+            day..=day
+        }
+    };
+
+    if days.is_empty() {
+        return Err(CliError::Usage(format!("Empty day range `{}`", value)));
+    }
+    Ok(days)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_day_range() {
+        let options = Options::parse(["--day", "3..=7", "--part", "2", "--timeout", "1.5", "-w"]);
+        let options = options.unwrap();
+        assert!(options.watch);
+        assert_eq!(options.days, DaySelection::Days(3..=7));
+        assert_eq!(options.part, Some(2));
+        assert_eq!(options.timeout, Some(Duration::from_millis(1500)));
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        let options = Options::parse(["bench", "--day", "1", "--runs", "5", "--save-baseline"]);
+        let options = options.unwrap();
+        assert_eq!(options.command, Command::Bench);
+        assert_eq!(options.bench.config.runs, 5);
+        assert!(options.bench.save_baseline);
+        assert!(Options::parse(["bench", "--runs", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_new_day() {
+        let options = Options::parse(["new-day", "5", "--year", "2023"]).unwrap();
+        assert_eq!(options.command, Command::NewDay);
+        assert_eq!(options.days, DaySelection::Days(5..=5));
+        assert_eq!(options.year, 2023);
+        assert!(Options::parse(["new-day"]).is_err());
+        assert!(Options::parse(["new-day", "3..=4"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_fetch() {
+        let options = Options::parse(["fetch", "--day", "1..=3"]).unwrap();
+        assert_eq!(options.command, Command::Fetch);
+        assert_eq!(options.days, DaySelection::Days(1..=3));
+        assert!(Options::parse(["fetch", "--input-archive", "x.tar.gz"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_submit() {
+        let options = Options::parse(["submit", "--day", "5", "--part", "2", "--answer", "42"]);
+        let options = options.unwrap();
+        assert_eq!(options.command, Command::Submit);
+        assert_eq!(options.answer.as_deref(), Some("42"));
+        assert!(Options::parse(["submit", "--day", "5"]).is_err());
+        assert!(Options::parse(["submit", "--day", "5..=6", "--part", "1"]).is_err());
+        assert!(Options::parse(["--day", "5", "--answer", "42"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_examples() {
+        let options = Options::parse(["examples", "--day", "3", "--html", "day3.html"]).unwrap();
+        assert_eq!(options.command, Command::Examples);
+        assert_eq!(options.html, Some(PathBuf::from("day3.html")));
+        assert!(Options::parse(["examples"]).is_err());
+        assert!(Options::parse(["--day", "3", "--html", "day3.html"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_status() {
+        let options = Options::parse(["status", "--year", "2023"]).unwrap();
+        assert_eq!(options.command, Command::Status);
+        assert_eq!(options.days, DaySelection::All);
+    }
+
+    #[test]
+    fn test_parse_repl() {
+        let options = Options::parse(["repl", "--day", "5"]).unwrap();
+        assert_eq!(options.command, Command::Repl);
+        assert_eq!(options.days, DaySelection::Days(5..=5));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(Options::parse(["--part", "3"]).unwrap_err().exit_code(), 2);
+        assert!(Options::parse(["--day", "7..=3"]).is_err());
+        assert!(Options::parse(["--day", "3..=4", "--input", "x.txt"]).is_err());
+        assert!(Options::parse(["--input", "x.txt"]).is_err());
+        assert!(Options::parse(["--jobs", "0"]).is_err());
+        assert!(Options::parse(["--timeout", "0"]).is_err());
+        assert!(Options::parse(["--timeout", "-1"]).is_err());
+        assert!(Options::parse(["--timeout", "inf"]).is_err());
+        let err = Options::parse(["--timeout", "1e300"]).unwrap_err();
+        assert_eq!(err.exit_code(), 2);
+        assert!(Options::parse(["--format", "xml"]).is_err());
+        assert!(Options::parse(["--check", "--format", "json"]).is_err());
+        assert!(Options::parse(["--watch", "--check"]).is_err());
+        assert!(Options::parse(["bench", "--watch"]).is_err());
+    }
+
+    #[test]
+    fn test_puzzles() {
+        let options = Options::parse(["--year", "2023", "--day", "24..=25"]).unwrap();
+        let puzzles = options.puzzles().unwrap().unwrap();
+        assert_eq!(
+            puzzles,
+            vec![
+                PuzzleId {
+                    year: 2023,
+                    day: 24
+                },
+                PuzzleId {
+                    year: 2023,
+                    day: 25
+                }
+            ]
+        );
+
+        let options = Options::parse(["--day", "25..=26"]).unwrap();
+        assert_eq!(options.puzzles(), Err(CliError::UnknownDay(26)));
+    }
+}
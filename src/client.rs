@@ -0,0 +1,236 @@
+use crate::input::{config_dirs, read_config};
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Site the puzzle inputs are downloaded from.
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable overriding the base URL.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+
+/// Environment variable holding the session token.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// File holding the session token, looked up like `aoc.toml`.
+pub const SESSION_FILE: &str = ".aoc-session";
+
+const USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " (puzzle input downloader)"
+);
+
+/// Status and body of an HTTP response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Minimal HTTP client, kept behind a trait so tests never touch the network.
+pub trait HttpClient: Send + Sync {
+    /// Sends a GET request to `url`. Error statuses are returned as a `Response`, only
+    /// transport failures are errors.
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> io::Result<Response>;
+
+    /// Sends `form` URL-encoded in a POST request to `url`, statuses are handled like `get`.
+    fn post(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+        form: &[(&str, &str)],
+    ) -> io::Result<Response>;
+}
+
+/// `HttpClient` backed by `ureq`.
+pub struct UreqClient {
+    agent: ureq::Agent,
+}
+
+impl Default for UreqClient {
+    fn default() -> Self {
+        UreqClient {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+}
+
+impl HttpClient for UreqClient {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> io::Result<Response> {
+        response(with_headers(self.agent.get(url), headers).call())
+    }
+
+    fn post(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+        form: &[(&str, &str)],
+    ) -> io::Result<Response> {
+        response(with_headers(self.agent.post(url), headers).send_form(form))
+    }
+}
+
+fn with_headers(request: ureq::Request, headers: &[(&str, &str)]) -> ureq::Request {
+    headers
+        .iter()
+        .fold(request, |request, (name, value)| request.set(name, value))
+}
+
+fn response(result: Result<ureq::Response, ureq::Error>) -> io::Result<Response> {
+    let response = match result {
+        Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+        Err(err) => return Err(io::Error::other(err)),
+    };
+    Ok(Response {
+        status: response.status(),
+        body: response.into_string()?,
+    })
+}
+
+/// Error for requests which need a session token when none was found.
+pub fn missing_session() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::PermissionDenied,
+        format!(
+            "No session token, set {} or store it in {}",
+            SESSION_ENV, SESSION_FILE
+        ),
+    )
+}
+
+/// Resolves the base URL from, in order: the `AOC_BASE_URL` environment variable,
+/// `base_url` in `aoc.toml`, and `BASE_URL`.
+pub fn base_url() -> String {
+    let url = std::env::var(BASE_URL_ENV)
+        .ok()
+        .filter(|url| !url.is_empty())
+        .or_else(|| {
+            config_dirs()
+                .into_iter()
+                .find_map(|dir| read_config(dir)?.base_url)
+        })
+        .unwrap_or_else(|| BASE_URL.to_string());
+    url.trim_end_matches('/').to_string()
+}
+
+/// Resolves the session token from, in order: the `AOC_SESSION` environment variable,
+/// the file named by `session_file` in `aoc.toml`, and `.aoc-session` in the working
+/// directory or the crate root. `None` if none of them holds a token.
+pub fn session() -> Option<String> {
+    if let Some(session) = std::env::var(SESSION_ENV)
+        .ok()
+        .filter(|session| !session.trim().is_empty())
+    {
+        return Some(session.trim().to_string());
+    }
+
+    let files = config_dirs().into_iter().flat_map(|dir| {
+        let configured = read_config(dir)
+            .and_then(|config| config.session_file)
+            .map(|file| dir.join(file));
+        configured.into_iter().chain([dir.join(SESSION_FILE)])
+    });
+    files
+        .filter_map(|file: PathBuf| std::fs::read_to_string(file).ok())
+        .map(|session| session.trim().to_string())
+        .find(|session| !session.is_empty())
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Serves one canned response on a local port and hands back the request, head and body.
+    pub(crate) fn stub_server(status: &str, body: &str) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let response = format!(
+            "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        );
+
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim_end().is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        length = value.trim().parse().unwrap();
+                    }
+                }
+                request.push_str(&line);
+            }
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+            request.push_str("\r\n");
+            request.push_str(&String::from_utf8(body).unwrap());
+
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            request
+        });
+        (url, server)
+    }
+
+    #[test]
+    fn test_ureq_client() {
+        let (url, server) = stub_server("200 OK", "1 2 3\n");
+        let client = UreqClient::default();
+        let response = client
+            .get(
+                &format!("{}/2024/day/1/input", url),
+                &[("Cookie", "session=abc")],
+            )
+            .unwrap();
+        assert_eq!(
+            response,
+            Response {
+                status: 200,
+                body: "1 2 3\n".to_string()
+            }
+        );
+
+        let head = server.join().unwrap();
+        assert!(head.starts_with("GET /2024/day/1/input HTTP/1.1"));
+        assert!(head.to_lowercase().contains("cookie: session=abc"));
+        assert!(head.contains(env!("CARGO_PKG_NAME")));
+    }
+
+    #[test]
+    fn test_ureq_client_status() {
+        let (url, server) = stub_server("404 Not Found", "Not found");
+        let response = UreqClient::default().get(&url, &[]).unwrap();
+        assert_eq!(response.status, 404);
+        assert_eq!(response.body, "Not found");
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_ureq_client_post() {
+        let (url, server) = stub_server("200 OK", "<article>Ok</article>");
+        let response = UreqClient::default()
+            .post(&url, &[], &[("level", "1"), ("answer", "a b")])
+            .unwrap();
+        assert_eq!(response.body, "<article>Ok</article>");
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST / HTTP/1.1"));
+        assert!(request.ends_with("\r\nlevel=1&answer=a+b"));
+    }
+}
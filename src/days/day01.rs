@@ -1,11 +1,13 @@
 use itertools::Itertools;
 
-use crate::Solution;
+use crate::{Answer, Solution, SolutionError, SolutionResult};
+
+crate::register_solution!(1, Day01 {});
 
 pub struct Day01 {}
 impl Solution for Day01 {
-    fn challenge1(&self, input: &str) -> String {
-        let (mut left, mut right) = parse_challenge(input);
+    fn challenge1(&self, input: &str) -> SolutionResult<Answer> {
+        let (mut left, mut right) = parse_challenge(input)?;
         let mut distance = 0;
 
         while !left.is_empty() {
@@ -15,11 +17,11 @@ impl Solution for Day01 {
             distance += (left_min - right_min).abs();
         }
 
-        distance.to_string()
+        Ok(distance.into())
     }
 
-    fn challenge2(&self, input: &str) -> String {
-        let (left, right) = parse_challenge(input);
+    fn challenge2(&self, input: &str) -> SolutionResult<Answer> {
+        let (left, right) = parse_challenge(input)?;
 
         let right_map = right.iter().counts();
         let mut distance = 0;
@@ -28,24 +30,44 @@ impl Solution for Day01 {
             distance += (elem as usize) * right_map.get(&elem).unwrap_or(&0);
         }
 
-        distance.to_string()
+        Ok(distance.into())
+    }
+
+    fn parse(&self, input: &str) -> Option<SolutionResult<()>> {
+        Some(parse_challenge(input).map(|_| ()))
     }
 }
 
-fn parse_challenge(input: &str) -> (Vec<i32>, Vec<i32>) {
+fn parse_challenge(input: &str) -> SolutionResult<(Vec<i32>, Vec<i32>)> {
     let mut left: Vec<i32> = Vec::new();
     let mut right: Vec<i32> = Vec::new();
 
-    for line in input.lines() {
+    for (ind, line) in input.lines().enumerate() {
         let mut iter = line.split_whitespace();
-        if let (Some(l), Some(r)) = (iter.next(), iter.next()) {
-            left.push(l.parse::<i32>().unwrap());
-            right.push(r.parse::<i32>().unwrap());
+        match (iter.next(), iter.next()) {
+            (Some(l), Some(r)) => {
+                left.push(parse_location(ind, line, l)?);
+                right.push(parse_location(ind, line, r)?);
+            }
+            (Some(l), None) => {
+                return Err(SolutionError::in_line(
+                    ind,
+                    line,
+                    l,
+                    "missing right location",
+                ));
+            }
+            _ => {}
         }
     }
-    assert_eq!(left.len(), right.len());
 
-    (left, right)
+    Ok((left, right))
+}
+
+fn parse_location(ind: usize, line: &str, token: &str) -> SolutionResult<i32> {
+    token
+        .parse::<i32>()
+        .map_err(|err| SolutionError::in_line(ind, line, token, err))
 }
 
 fn pop_minimum(list: &mut Vec<i32>) -> i32 {
@@ -64,7 +86,7 @@ fn pop_minimum(list: &mut Vec<i32>) -> i32 {
 mod tests {
     use super::*;
 
-    const INPUT: &'static str = "\
+    const INPUT: &str = "\
 3   4
 4   3
 2   5
@@ -76,12 +98,19 @@ mod tests {
     #[test]
     fn test_challenge1() {
         let day: Day01 = Day01 {};
-        assert_eq!(day.challenge1(INPUT), "11");
+        assert_eq!(day.challenge1(INPUT).unwrap(), 11);
     }
 
     #[test]
     fn test_challenge2() {
         let day = Day01 {};
-        assert_eq!(day.challenge2(INPUT), "31");
+        assert_eq!(day.challenge2(INPUT).unwrap(), 31);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let day = Day01 {};
+        let err = day.challenge1("3   4\n4   x3\n").unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), Some(5)));
     }
 }
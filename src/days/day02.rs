@@ -1,76 +1,94 @@
-use crate::Solution;
+use crate::{Answer, Solution, SolutionError, SolutionResult};
+
+crate::register_solution!(2, Day02);
 
 pub struct Day02;
 impl Solution for Day02 {
-    fn challenge1(&self, input: &str) -> String {
-        input.lines()
-            .map(|line| Report::from_line(line))
+    fn challenge1(&self, input: &str) -> SolutionResult<Answer> {
+        let reports = parse_reports(input)?;
+        Ok(reports
+            .iter()
             .filter(|report| match report.check_report() {
                 ReportStatus::Safe(_) => true,
                 ReportStatus::Unsafe => false,
             })
             .count()
-            .to_string()
+            .into())
     }
 
-    fn challenge2(&self, input: &str) -> String {
-        input.lines()
-            .map(|line| Report::from_line(line))
+    fn challenge2(&self, input: &str) -> SolutionResult<Answer> {
+        let reports = parse_reports(input)?;
+        Ok(reports
+            .iter()
             .filter(|report| match report.tolerant_report_check() {
                 ReportStatus::Safe(_) => true,
                 ReportStatus::Unsafe => false,
             })
             .count()
-            .to_string()
+            .into())
+    }
+
+    fn parse(&self, input: &str) -> Option<SolutionResult<()>> {
+        Some(parse_reports(input).map(|_| ()))
     }
 }
 
+fn parse_reports(input: &str) -> SolutionResult<Vec<Report>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(ind, line)| Report::from_line(ind, line))
+        .collect()
+}
+
 struct Report {
-    levels: Vec<u32>
+    levels: Vec<u32>,
 }
 
 enum ReportDirection {
-    INCREASING,
-    DECREASING
+    Increasing,
+    Decreasing,
 }
 
 enum ReportStatus {
     Safe(ReportDirection),
-    Unsafe
+    Unsafe,
 }
 
 impl Report {
-    fn from_line(line: &str) -> Self {
-        let levels: Vec<u32> = line.split_whitespace().map(|x| x.parse().unwrap()).collect();
-        Self {
-            levels
-        }
+    fn from_line(ind: usize, line: &str) -> SolutionResult<Self> {
+        let levels: Vec<u32> = line
+            .split_whitespace()
+            .map(|x| {
+                x.parse()
+                    .map_err(|err| SolutionError::in_line(ind, line, x, err))
+            })
+            .collect::<SolutionResult<_>>()?;
+        Ok(Self { levels })
     }
 
     fn check_report(&self) -> ReportStatus {
         let levels_iter = self.levels.iter();
         let increasing = levels_iter.clone().is_sorted();
         let decreasing = levels_iter.clone().rev().is_sorted();
-        let level_diff_valid = self.levels.windows(2)
-            .all(|window| {
-                let diff = window[1].abs_diff(window[0]);
-                diff >= 1 && diff <=3
+        let level_diff_valid = self.levels.windows(2).all(|window| {
+            let diff = window[1].abs_diff(window[0]);
+            (1..=3).contains(&diff)
         });
 
-
         if level_diff_valid {
             if increasing {
-                return ReportStatus::Safe(ReportDirection::INCREASING);
+                return ReportStatus::Safe(ReportDirection::Increasing);
             }
             if decreasing {
-                return ReportStatus::Safe(ReportDirection::DECREASING);
+                return ReportStatus::Safe(ReportDirection::Decreasing);
             }
         }
         ReportStatus::Unsafe
     }
 
     fn tolerant_report_check(&self) -> ReportStatus {
-        if let ReportStatus::Safe(direction)= self.check_report() {
+        if let ReportStatus::Safe(direction) = self.check_report() {
             return ReportStatus::Safe(direction);
         }
 
@@ -84,15 +102,13 @@ impl Report {
         }
         ReportStatus::Unsafe
     }
-
-    
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &'static str = "\
+    const INPUT: &str = "\
 7 6 4 2 1
 1 2 7 8 9
 9 7 6 2 1
@@ -104,12 +120,12 @@ mod tests {
     #[test]
     fn test_challenge1() {
         let day = Day02 {};
-        assert_eq!(day.challenge1(INPUT), "2");
+        assert_eq!(day.challenge1(INPUT).unwrap(), 2);
     }
 
     #[test]
     fn test_challenge2() {
         let day = Day02 {};
-        assert_eq!(day.challenge2(INPUT), "4");
+        assert_eq!(day.challenge2(INPUT).unwrap(), 4);
     }
-}
\ No newline at end of file
+}
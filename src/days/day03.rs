@@ -0,0 +1,78 @@
+use anyhow::Result;
+use regex::Regex;
+use std::io::{self, BufRead};
+
+use crate::{Answer, Solution, SolutionResult};
+
+crate::register_solution!(3, Day03 {});
+
+pub struct Day03 {}
+impl Solution for Day03 {
+    fn challenge1(&self, input: &str) -> SolutionResult<Answer> {
+        Ok(part1(input.as_bytes())?.into())
+    }
+
+    fn challenge2(&self, input: &str) -> SolutionResult<Answer> {
+        Ok(part2(input.as_bytes())?.into())
+    }
+}
+
+fn part1<R: BufRead>(reader: R) -> Result<usize> {
+    let input: String = reader.lines().map_while(io::Result::ok).collect();
+    let re = Regex::new(r"mul\(([\d]{1,3}),([\d]{1,3})\)")?;
+    let mul_params = re.captures_iter(&input).map(|caps| {
+        let (_, [x, y]) = caps.extract();
+        (x, y)
+    });
+
+    let mut res = 0;
+    for (x, y) in mul_params {
+        res += x.parse::<i32>()? * y.parse::<i32>()?;
+    }
+
+    Ok(res as usize)
+}
+
+fn part2<R: BufRead>(reader: R) -> Result<usize> {
+    let input: String = reader.lines().map_while(io::Result::ok).collect();
+    let re = Regex::new(r"don't\(\).*?do\(\)")?;
+    let cleared_text = re.replace_all(&input, "");
+
+    let re = Regex::new(r"mul\(([\d]{1,3}),([\d]{1,3})\)")?;
+    let mul_params = re.captures_iter(&cleared_text).map(|caps| {
+        let (_, [x, y]) = caps.extract();
+        (x, y)
+    });
+
+    let mut res = 0;
+    for (x, y) in mul_params {
+        res += x.parse::<i32>()? * y.parse::<i32>()?;
+    }
+
+    Ok(res as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "\
+xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))
+";
+
+    const INPUT2: &str = "\
+xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))
+";
+
+    #[test]
+    fn test_challenge1() {
+        let day = Day03 {};
+        assert_eq!(day.challenge1(INPUT).unwrap(), 161);
+    }
+
+    #[test]
+    fn test_challenge2() {
+        let day = Day03 {};
+        assert_eq!(day.challenge2(INPUT2).unwrap(), 48);
+    }
+}
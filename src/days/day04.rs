@@ -0,0 +1,110 @@
+use crate::geometry::Direction8;
+use crate::grid::Position;
+use crate::{Answer, Grid, Solution, SolutionResult};
+
+crate::register_solution!(4, Day04 {});
+
+pub struct Day04 {}
+impl Solution for Day04 {
+    fn challenge1(&self, input: &str) -> SolutionResult<Answer> {
+        Ok(part1(input)?.into())
+    }
+
+    fn challenge2(&self, input: &str) -> SolutionResult<Answer> {
+        Ok(part2(input)?.into())
+    }
+
+    fn parse(&self, input: &str) -> Option<SolutionResult<()>> {
+        Some(input.parse::<Grid<char>>().map(|_| ()))
+    }
+}
+
+fn part1(input: &str) -> SolutionResult<usize> {
+    let grid: Grid<char> = input.parse()?;
+    let find_str = "XMAS";
+
+    let words_found = grid
+        .positions()
+        .flat_map(|position| Direction8::ALL.map(|direction| (position, direction)))
+        .filter(|&(position, direction)| {
+            grid.ray(position, direction.offset())
+                .map(|(_, &c)| c)
+                .take(find_str.len())
+                .eq(find_str.chars())
+        })
+        .count();
+
+    Ok(words_found)
+}
+
+fn check_pattern(grid: &Grid<char>, position: Position) -> bool {
+    /*
+       M.S     M.M     S.M     S.S
+       .A.     .A.     .A.     .A.
+       M.S     S.S     S.M     M.M
+    */
+
+    let corner = |offset| grid.offset(position, offset).map(|corner| grid[corner]);
+    let is_mas = |a, b| {
+        matches!(
+            (corner(a), corner(b)),
+            (Some('M'), Some('S')) | (Some('S'), Some('M'))
+        )
+    };
+
+    is_mas((-1, -1), (1, 1)) && is_mas((-1, 1), (1, -1))
+}
+
+fn part2(input: &str) -> SolutionResult<usize> {
+    let grid: Grid<char> = input.parse()?;
+
+    let words_found = grid
+        .iter()
+        .filter(|&(position, &c)| c == 'A' && check_pattern(&grid, position))
+        .count();
+
+    Ok(words_found)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+";
+
+    const INPUT2: &str = "\
+.M.S......
+..A..MSMS.
+.M.S.MAA..
+..A.ASMSM.
+.M.S.M....
+..........
+S.S.S.S.S.
+.A.A.A.A..
+M.M.M.M.M.
+..........
+";
+
+    #[test]
+    fn test_challenge1() {
+        let day = Day04 {};
+        assert_eq!(day.challenge1(INPUT).unwrap(), 18);
+    }
+
+    #[test]
+    fn test_challenge2() {
+        let day = Day04 {};
+        assert_eq!(day.challenge2(INPUT2).unwrap(), 9);
+    }
+}
@@ -0,0 +1,174 @@
+use itertools::{enumerate, Itertools};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::sections::{Section, Sections};
+use crate::{Answer, Solution, SolutionResult};
+
+crate::register_solution!(5, Day05 {});
+
+pub struct Day05 {}
+impl Solution for Day05 {
+    fn challenge1(&self, input: &str) -> SolutionResult<Answer> {
+        Ok(part1(input)?.into())
+    }
+
+    fn challenge2(&self, input: &str) -> SolutionResult<Answer> {
+        Ok(part2(input)?.into())
+    }
+
+    fn parse(&self, input: &str) -> Option<SolutionResult<()>> {
+        Some(read_input(input).map(|_| ()))
+    }
+}
+
+type Rules = HashMap<i32, Vec<i32>>;
+
+fn create_rules(section: &Section) -> SolutionResult<Rules> {
+    let mut rules = Rules::new();
+    for line in section.lines() {
+        let (page, page_rule) = line.split_once("|")?;
+
+        rules
+            .entry(page.parse()?)
+            .or_default()
+            .push(page_rule.parse()?);
+    }
+    Ok(rules)
+}
+
+fn create_updates(section: &Section) -> SolutionResult<Vec<Vec<i32>>> {
+    section.parse_lines(|line| line.list(","))
+}
+
+fn read_input(input: &str) -> SolutionResult<(Rules, Vec<Vec<i32>>)> {
+    Sections::new(input).parse((create_rules, create_updates))
+}
+
+fn check_rules(rules: &Rules, update: &[i32]) -> bool {
+    for (ind, page) in enumerate(update) {
+        let pages_before = &update[0..ind];
+
+        for earlier_page in pages_before {
+            if let Some(rule) = rules.get(page) {
+                if rule.contains(earlier_page) {
+                    return false;
+                }
+            }
+        }
+    }
+    true
+}
+
+fn part1(input: &str) -> SolutionResult<usize> {
+    let (rules_map, updates_list) = read_input(input)?;
+    let mut valid_updates: Vec<Vec<i32>> = Vec::new();
+
+    for update in updates_list {
+        if check_rules(&rules_map, &update) {
+            valid_updates.push(update);
+        }
+    }
+
+    let mut median_sum = 0;
+    for update in valid_updates {
+        median_sum += update[update.len() / 2];
+    }
+
+    Ok(median_sum as usize)
+}
+
+fn fix_invalid_update(update: &[i32], rules: &Rules) -> Vec<i32> {
+    let mut valid_update = update.to_vec();
+    valid_update.sort_by(|a, b| match rules.get(b) {
+        None => Ordering::Equal,
+        Some(rule) => {
+            if rule.iter().contains(a) {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }
+        }
+    });
+    valid_update
+}
+
+fn part2(input: &str) -> SolutionResult<usize> {
+    let (rules_map, updates_list) = read_input(input)?;
+    let mut invalid_updates: Vec<Vec<i32>> = Vec::new();
+
+    for update in updates_list {
+        if !check_rules(&rules_map, &update) {
+            invalid_updates.push(update);
+        }
+    }
+
+    let mut valid_updates: Vec<Vec<i32>> = Vec::new();
+    for update in invalid_updates {
+        valid_updates.push(fix_invalid_update(&update, &rules_map));
+    }
+
+    let mut median_sum = 0;
+    for update in valid_updates {
+        median_sum += update[update.len() / 2];
+    }
+
+    Ok(median_sum as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+";
+
+    #[test]
+    fn test_challenge1() {
+        let day = Day05 {};
+        assert_eq!(day.challenge1(INPUT).unwrap(), 143);
+    }
+
+    #[test]
+    fn test_challenge2() {
+        let day = Day05 {};
+        assert_eq!(day.challenge2(INPUT).unwrap(), 123);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let day = Day05 {};
+        let err = day.challenge1("47|53\n75,47\n").unwrap_err();
+        assert_eq!(err.message, "Expected 2 section(s), found 1");
+        let err = day.challenge1("47|53\n\n75,47\n\n61\n").unwrap_err();
+        assert_eq!(err.line, Some(5));
+    }
+}
@@ -0,0 +1,204 @@
+use crate::geometry::Direction4;
+use crate::grid::Position;
+use crate::{Answer, Grid, Solution, SolutionError, SolutionResult};
+
+crate::register_solution!(6, Day06 {});
+
+pub struct Day06 {}
+impl Solution for Day06 {
+    fn challenge1(&self, input: &str) -> SolutionResult<Answer> {
+        Ok(part1(input)?.into())
+    }
+
+    fn challenge2(&self, input: &str) -> SolutionResult<Answer> {
+        Ok(part2(input)?.into())
+    }
+
+    fn parse(&self, input: &str) -> Option<SolutionResult<()>> {
+        Some(input.parse::<Grid<char>>().map(|_| ()))
+    }
+}
+
+fn get_pos(map: &Grid<char>) -> SolutionResult<(Position, Direction4)> {
+    map.iter()
+        .find_map(|(position, &c)| Direction4::from_glyph(c).map(|direction| (position, direction)))
+        .ok_or_else(|| SolutionError::new("Guard not found"))
+}
+
+/**
+ * Processes the next move. Returns true if guard left the map
+ */
+fn next_move(map: &mut Grid<char>) -> SolutionResult<bool> {
+    let (pos, direction) = get_pos(map)?;
+
+    let Some(next_pos) = map.offset(pos, direction.offset()) else {
+        map[pos] = 'X';
+        return Ok(true);
+    };
+
+    let next_char = map[next_pos];
+    let curr_char = &mut map[pos];
+
+    let mut set_next_char = '.';
+
+    match next_char {
+        '#' => {
+            set_next_char = '#';
+            *curr_char = direction.turn_right().glyph();
+        }
+        '.' | 'X' => {
+            *curr_char = 'X';
+            set_next_char = direction.glyph();
+        }
+        _ => {}
+    }
+
+    map[next_pos] = set_next_char;
+    Ok(false)
+}
+
+fn count_visits(map: &Grid<char>) -> usize {
+    map.iter().filter(|&(_, &c)| c == 'X').count()
+}
+
+fn part1(input: &str) -> SolutionResult<usize> {
+    let mut map: Grid<char> = input.parse()?;
+    while !next_move(&mut map)? {}
+    Ok(count_visits(&map))
+}
+
+fn search(
+    direction: Direction4,
+    map: &Grid<char>,
+    remember_direction: &Grid<Option<Direction4>>,
+    pos: Position,
+) -> bool {
+    let Some(next_pos) = map.offset(pos, direction.offset()) else {
+        return false;
+    };
+
+    if let Some(next_dir) = remember_direction[next_pos] {
+        return next_dir == direction;
+    }
+
+    if map[next_pos] == '#' {
+        return false;
+    }
+
+    search(direction, map, remember_direction, next_pos)
+}
+
+/**
+ * Looks if a path to a loop is possible from the current position, returns true if so.
+ */
+fn search_for_continuing_trail(
+    map: &Grid<char>,
+    remember_direction: &Grid<Option<Direction4>>,
+    pos: Position,
+    cur_direction: Direction4,
+) -> bool {
+    search(cur_direction.turn_right(), map, remember_direction, pos)
+}
+
+fn next_move2(
+    initial_pos: Position,
+    map: &mut Grid<char>,
+    counter: &mut usize,
+    remember_direction: &mut Grid<Option<Direction4>>,
+) -> SolutionResult<bool> {
+    let (pos, direction) = get_pos(map)?;
+    remember_direction[pos] = Some(direction);
+
+    let Some(next_pos) = map.offset(pos, direction.offset()) else {
+        map[pos] = 'X';
+        return Ok(true);
+    };
+
+    let next_char = map[next_pos];
+
+    let mut set_next_char = '.';
+
+    match next_char {
+        '#' => {
+            set_next_char = '#';
+            map[pos] = direction.turn_right().glyph();
+        }
+        '.' => {
+            map[pos] = 'X';
+            set_next_char = direction.glyph();
+
+            if search_for_continuing_trail(map, remember_direction, pos, direction) {
+                *counter += 1;
+            }
+        }
+
+        'X' => {
+            map[pos] = 'X';
+            set_next_char = direction.glyph();
+
+            if next_pos != initial_pos {
+                if let Some(next_direction) = remember_direction[next_pos] {
+                    // The path crossed here before, heading to where the guard would turn to
+                    if next_direction == direction.turn_right() {
+                        *counter += 1;
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+
+    map[next_pos] = set_next_char;
+    Ok(false)
+}
+
+fn part2(input: &str) -> SolutionResult<usize> {
+    let mut map: Grid<char> = input.parse()?;
+
+    let mut remember_direction: Grid<Option<Direction4>> =
+        Grid::new(map.width(), map.height(), None);
+
+    let mut counter: usize = 0;
+
+    let (initial_pos, _) = get_pos(&map)?;
+    while !next_move2(initial_pos, &mut map, &mut counter, &mut remember_direction)? {}
+
+    Ok(counter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+    #[test]
+    fn test_challenge1() {
+        let day = Day06 {};
+        assert_eq!(day.challenge1(INPUT).unwrap(), 41);
+    }
+
+    #[test]
+    fn test_challenge2() {
+        let day = Day06 {};
+        assert_eq!(day.challenge2(INPUT).unwrap(), 6);
+    }
+
+    #[test]
+    fn test_missing_guard() {
+        let day = Day06 {};
+        let err = day.challenge1("..#\n...\n").unwrap_err();
+        assert_eq!(err.message, "Guard not found");
+    }
+}
@@ -0,0 +1,234 @@
+use crate::{parse, Answer, Solution, SolutionError, SolutionResult};
+
+crate::register_solution!(7, Day07 {});
+
+pub struct Day07 {}
+impl Solution for Day07 {
+    fn challenge1(&self, input: &str) -> SolutionResult<Answer> {
+        Ok(part1(input)?.into())
+    }
+
+    fn challenge2(&self, input: &str) -> SolutionResult<Answer> {
+        Ok(part2(input)?.into())
+    }
+
+    fn parse(&self, input: &str) -> Option<SolutionResult<()>> {
+        Some(read_operations(input).map(|_| ()))
+    }
+}
+
+#[derive(Clone, Hash, Eq, PartialEq, Debug)]
+enum Operation {
+    Multiply,
+    Add,
+}
+
+fn read_operations(input: &str) -> SolutionResult<Vec<(u64, Vec<u64>)>> {
+    parse::lines(input)
+        .map(|line| {
+            let (result, operands) = line.split_once(":")?;
+            Ok((result.parse()?, operands.numbers()?))
+        })
+        .collect()
+}
+
+/// `None` when the result doesn't fit into a `u64`, which no target can be.
+fn perform_operation(operation: Operation, left_operand: u64, right_operand: u64) -> Option<u64> {
+    match operation {
+        Operation::Multiply => left_operand.checked_mul(right_operand),
+        Operation::Add => left_operand.checked_add(right_operand),
+    }
+}
+
+fn create_permutations(len: usize) -> Vec<Vec<Operation>> {
+    if len == 0 {
+        return vec![vec![]];
+    }
+
+    let mut result = vec![];
+    for perm in create_permutations(len - 1) {
+        let mut multiply_perm = perm.clone();
+        multiply_perm.push(Operation::Multiply);
+        result.push(multiply_perm);
+
+        let mut add_perm = perm;
+        add_perm.push(Operation::Add);
+        result.push(add_perm);
+    }
+    result
+}
+
+fn check_operation(exp_result: u64, operands: &[u64]) -> bool {
+    let mut operands = operands.to_vec();
+    operands.reverse();
+
+    let permutations = create_permutations(operands.len().saturating_sub(1));
+    for operations in permutations {
+        let mut current_operands = operands.clone();
+
+        let Some(first) = current_operands.pop() else {
+            return false;
+        };
+
+        let res = operations
+            .into_iter()
+            .zip(current_operands.into_iter().rev())
+            .try_fold(first, |res, (operation, next_operand)| {
+                perform_operation(operation, res, next_operand)
+            });
+
+        if res == Some(exp_result) {
+            return true;
+        }
+    }
+    false
+}
+
+fn part1(input: &str) -> SolutionResult<u64> {
+    let operations = read_operations(input)?;
+    operations
+        .iter()
+        .filter(|(result, operands)| check_operation(*result, operands))
+        .try_fold(0_u64, |count, (result, _operands)| {
+            count.checked_add(*result)
+        })
+        .ok_or_else(|| SolutionError::new("The total doesn't fit into a u64"))
+}
+
+#[derive(Clone, Hash, Eq, PartialEq, Debug)]
+enum Operation2 {
+    Multiply,
+    Add,
+    Concatenate,
+}
+
+/// `None` when the result doesn't fit into a `u64`, like `perform_operation`.
+fn perform_operation2(operation: Operation2, left_operand: u64, right_operand: u64) -> Option<u64> {
+    match operation {
+        Operation2::Multiply => left_operand.checked_mul(right_operand),
+        Operation2::Add => left_operand.checked_add(right_operand),
+        Operation2::Concatenate => {
+            let digits = right_operand.checked_ilog10().unwrap_or(0) + 1;
+            left_operand
+                .checked_mul(10_u64.checked_pow(digits)?)?
+                .checked_add(right_operand)
+        }
+    }
+}
+
+fn create_permutations2(len: usize) -> Vec<Vec<Operation2>> {
+    if len == 0 {
+        return vec![vec![]];
+    }
+
+    let mut result = vec![];
+    for perm in create_permutations2(len - 1) {
+        let mut multiply_perm = perm.clone();
+        multiply_perm.push(Operation2::Multiply);
+        result.push(multiply_perm);
+
+        let mut add_perm = perm.clone();
+        add_perm.push(Operation2::Add);
+        result.push(add_perm);
+
+        let mut concat_perm = perm;
+        concat_perm.push(Operation2::Concatenate);
+        result.push(concat_perm);
+    }
+    result
+}
+
+fn check_operation2(exp_result: u64, operands: &[u64]) -> bool {
+    let mut operands = operands.to_vec();
+    operands.reverse();
+
+    let permutations = create_permutations2(operands.len().saturating_sub(1));
+    for operations in permutations {
+        let mut current_operands = operands.clone();
+
+        let Some(first) = current_operands.pop() else {
+            return false;
+        };
+
+        let res = operations
+            .into_iter()
+            .zip(current_operands.into_iter().rev())
+            .try_fold(first, |res, (operation, next_operand)| {
+                perform_operation2(operation, res, next_operand)
+            });
+
+        if res == Some(exp_result) {
+            return true;
+        }
+    }
+    false
+}
+
+fn part2(input: &str) -> SolutionResult<u64> {
+    let operations = read_operations(input)?;
+    operations
+        .iter()
+        .filter(|(result, operands)| check_operation2(*result, operands))
+        .try_fold(0_u64, |count, (result, _operands)| {
+            count.checked_add(*result)
+        })
+        .ok_or_else(|| SolutionError::new("The total doesn't fit into a u64"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+";
+
+    #[test]
+    fn test_challenge1() {
+        let day = Day07 {};
+        assert_eq!(day.challenge1(INPUT).unwrap(), 3749);
+    }
+
+    #[test]
+    fn test_challenge2() {
+        let day = Day07 {};
+        assert_eq!(day.challenge2(INPUT).unwrap(), 11387);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let err = read_operations("190: 10 19\n3267 81 40\n83: 17 x5\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 11: Expected `:`");
+        let err = read_operations("83: 17 x5\n").unwrap_err();
+        assert_eq!((err.line, err.column), (Some(1), Some(8)));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let input = "\
+18446744073709551615: 4294967296 4294967296
+18446744073709551615: 18446744073709551614 1
+18446744073709551615: 18446744073709551615 2 0
+";
+        assert_eq!(part1(input).unwrap(), u64::MAX);
+        let err = part2(&input.replace(" 2 0", " 1 0")).unwrap_err();
+        assert_eq!(err.message, "The total doesn't fit into a u64");
+        assert!(!check_operation(u64::MAX, &[u64::MAX, 2, 1]));
+
+        assert!(check_operation2(u64::MAX, &[1844674407370955161, 5]));
+        assert!(!check_operation2(u64::MAX, &[18446744073709551615, 10]));
+        assert!(!check_operation2(u64::MAX, &[u64::MAX / 2, 3, 1]));
+        assert_eq!(
+            perform_operation2(Operation2::Concatenate, 12, 0),
+            Some(120)
+        );
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{Answer, Solution, SolutionResult};
+
+crate::register_solution!(8, Day08 {});
+
+pub struct Day08 {}
+impl Solution for Day08 {
+    fn challenge1(&self, _input: &str) -> SolutionResult<Answer> {
+        Ok(Answer::Unsolved)
+    }
+
+    fn challenge2(&self, _input: &str) -> SolutionResult<Answer> {
+        Ok(Answer::Unsolved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+";
+
+    #[test]
+    #[ignore = "not solved yet"]
+    fn test_challenge1() {
+        let day = Day08 {};
+        assert_eq!(day.challenge1(INPUT).unwrap(), 14);
+    }
+}
@@ -0,0 +1,30 @@
+use crate::{Answer, Solution, SolutionResult};
+
+crate::register_solution!(9, Day09 {});
+
+pub struct Day09 {}
+impl Solution for Day09 {
+    fn challenge1(&self, _input: &str) -> SolutionResult<Answer> {
+        Ok(Answer::Unsolved)
+    }
+
+    fn challenge2(&self, _input: &str) -> SolutionResult<Answer> {
+        Ok(Answer::Unsolved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "\
+2333133121414131402
+";
+
+    #[test]
+    #[ignore = "not solved yet"]
+    fn test_challenge1() {
+        let day = Day09 {};
+        assert_eq!(day.challenge1(INPUT).unwrap(), 1918);
+    }
+}
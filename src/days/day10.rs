@@ -0,0 +1,33 @@
+use crate::{Answer, Solution, SolutionResult};
+
+crate::register_solution!(10, Day10 {});
+
+pub struct Day10 {}
+impl Solution for Day10 {
+    fn challenge1(&self, _input: &str) -> SolutionResult<Answer> {
+        Ok(Answer::Unsolved)
+    }
+
+    fn challenge2(&self, _input: &str) -> SolutionResult<Answer> {
+        Ok(Answer::Unsolved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "\
+0123
+1234
+8765
+9876
+";
+
+    #[test]
+    #[ignore = "not solved yet"]
+    fn test_challenge1() {
+        let day = Day10 {};
+        assert_eq!(day.challenge1(INPUT).unwrap(), 36);
+    }
+}
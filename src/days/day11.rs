@@ -0,0 +1,30 @@
+use crate::{Answer, Solution, SolutionResult};
+
+crate::register_solution!(11, Day11 {});
+
+pub struct Day11 {}
+impl Solution for Day11 {
+    fn challenge1(&self, _input: &str) -> SolutionResult<Answer> {
+        Ok(Answer::Unsolved)
+    }
+
+    fn challenge2(&self, _input: &str) -> SolutionResult<Answer> {
+        Ok(Answer::Unsolved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "\
+125 17
+";
+
+    #[test]
+    #[ignore = "not solved yet"]
+    fn test_challenge1() {
+        let day = Day11 {};
+        assert_eq!(day.challenge1(INPUT).unwrap(), 55312);
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{Answer, Solution, SolutionResult};
+
+crate::register_solution!(12, Day12 {});
+
+pub struct Day12 {}
+impl Solution for Day12 {
+    fn challenge1(&self, _input: &str) -> SolutionResult<Answer> {
+        Ok(Answer::Unsolved)
+    }
+
+    fn challenge2(&self, _input: &str) -> SolutionResult<Answer> {
+        Ok(Answer::Unsolved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "\
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE
+";
+
+    #[test]
+    #[ignore = "not solved yet"]
+    fn test_challenge1() {
+        let day = Day12 {};
+        assert_eq!(day.challenge1(INPUT).unwrap(), 1930);
+    }
+}
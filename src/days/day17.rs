@@ -0,0 +1,269 @@
+use anyhow::{bail, Context, Error, Result};
+use itertools::Itertools;
+
+use crate::sections::{Section, Sections};
+use crate::{Answer, Solution, SolutionResult};
+
+crate::register_solution!(17, Day17 {});
+
+pub struct Day17 {}
+impl Solution for Day17 {
+    fn challenge1(&self, input: &str) -> SolutionResult<Answer> {
+        Ok(part1(input)?.into())
+    }
+
+    fn challenge2(&self, _input: &str) -> SolutionResult<Answer> {
+        Ok(Answer::Unsolved)
+    }
+
+    fn parse(&self, input: &str) -> Option<SolutionResult<()>> {
+        Some(Computer::from_input(input).map(|_| ()))
+    }
+}
+
+trait Instruction {
+    fn run(&self, computer: &mut Computer);
+}
+
+struct Adv {
+    numerator: u32,
+    exponent: u32,
+}
+
+impl Instruction for Adv {
+    fn run(&self, computer: &mut Computer) {
+        computer.reg_a = divide_by_power_of_two(self.numerator, self.exponent);
+        computer.instruction_pointer += 2;
+    }
+}
+
+/// `numerator / 2^exponent`, which is 0 once the power doesn't fit into a `u32` any more.
+fn divide_by_power_of_two(numerator: u32, exponent: u32) -> u32 {
+    numerator.checked_shr(exponent).unwrap_or(0)
+}
+
+struct Bxl {
+    left_operand: u32,
+    right_operand: u32,
+}
+
+impl Instruction for Bxl {
+    fn run(&self, computer: &mut Computer) {
+        computer.reg_b = self.left_operand ^ self.right_operand;
+        computer.instruction_pointer += 2;
+    }
+}
+
+struct Bst {
+    operand: u32,
+}
+
+impl Instruction for Bst {
+    fn run(&self, computer: &mut Computer) {
+        computer.reg_b = self.operand % 8;
+        computer.instruction_pointer += 2;
+    }
+}
+
+struct Jnz {
+    operand: u32,
+}
+
+impl Instruction for Jnz {
+    fn run(&self, computer: &mut Computer) {
+        if computer.reg_a != 0 {
+            computer.instruction_pointer = self.operand as usize;
+        } else {
+            computer.instruction_pointer += 2;
+        }
+    }
+}
+
+struct Bxc {}
+
+impl Instruction for Bxc {
+    fn run(&self, computer: &mut Computer) {
+        computer.reg_b ^= computer.reg_c;
+        computer.instruction_pointer += 2;
+    }
+}
+
+struct Out {
+    operand: u32,
+}
+
+impl Instruction for Out {
+    fn run(&self, computer: &mut Computer) {
+        computer.output.push(self.operand % 8);
+        computer.instruction_pointer += 2;
+    }
+}
+
+struct Bdv {
+    numerator: u32,
+    exponent: u32,
+}
+
+impl Instruction for Bdv {
+    fn run(&self, computer: &mut Computer) {
+        computer.reg_b = divide_by_power_of_two(self.numerator, self.exponent);
+        computer.instruction_pointer += 2;
+    }
+}
+
+struct Cdv {
+    numerator: u32,
+    exponent: u32,
+}
+
+impl Instruction for Cdv {
+    fn run(&self, computer: &mut Computer) {
+        computer.reg_c = divide_by_power_of_two(self.numerator, self.exponent);
+        computer.instruction_pointer += 2;
+    }
+}
+
+struct Computer {
+    reg_a: u32,
+    reg_b: u32,
+    reg_c: u32,
+    program: Vec<u8>,
+    instruction_pointer: usize,
+    output: Vec<u32>,
+}
+
+impl Computer {
+    fn from_input(input: &str) -> SolutionResult<Self> {
+        let registers = |section: &Section| -> SolutionResult<[u32; 3]> {
+            let headers = section.headers(":")?;
+            Ok([
+                headers.get("Register A")?.parse()?,
+                headers.get("Register B")?.parse()?,
+                headers.get("Register C")?.parse()?,
+            ])
+        };
+        let program = |section: &Section| section.headers(":")?.get("Program")?.list(",");
+        let ([reg_a, reg_b, reg_c], program) = Sections::new(input).parse((registers, program))?;
+
+        Ok(Self {
+            reg_a,
+            reg_b,
+            reg_c,
+            program,
+            instruction_pointer: 0,
+            output: vec![],
+        })
+    }
+
+    fn get_value_for_combo_operand(&self, combo_operand: u8) -> Result<u32> {
+        match combo_operand {
+            0_u8..=3_u8 => Ok(combo_operand as u32),
+            4 => Ok(self.reg_a),
+            5 => Ok(self.reg_b),
+            6 => Ok(self.reg_c),
+            _ => Err(Error::msg("Invalid combo operand")),
+        }
+    }
+
+    fn next_instruction(&mut self) -> Result<Box<dyn Instruction>> {
+        let opcode = self.program[self.instruction_pointer];
+        let literal_operand = *self
+            .program
+            .get(self.instruction_pointer + 1)
+            .context("Missing operand")?;
+        let combo_operand = self.get_value_for_combo_operand(literal_operand)?;
+
+        match opcode {
+            0 => Ok(Box::new(Adv {
+                numerator: self.reg_a,
+                exponent: combo_operand,
+            })),
+            1 => Ok(Box::new(Bxl {
+                left_operand: self.reg_b,
+                right_operand: literal_operand as u32,
+            })),
+            2 => Ok(Box::new(Bst {
+                operand: combo_operand,
+            })),
+            3 => Ok(Box::new(Jnz {
+                operand: literal_operand as u32,
+            })),
+            4 => Ok(Box::new(Bxc {})),
+            5 => Ok(Box::new(Out {
+                operand: combo_operand,
+            })),
+            6 => Ok(Box::new(Bdv {
+                numerator: self.reg_a,
+                exponent: combo_operand,
+            })),
+            7 => Ok(Box::new(Cdv {
+                numerator: self.reg_a,
+                exponent: combo_operand,
+            })),
+            _ => bail!("Invalid opcode"),
+        }
+    }
+
+    fn run(&mut self) -> Result<String> {
+        while self.instruction_pointer < self.program.len() {
+            self.next_instruction()?.run(self);
+        }
+        Ok(self.output.iter().join(","))
+    }
+}
+
+fn part1(input: &str) -> SolutionResult<String> {
+    let mut computer = Computer::from_input(input)?;
+    Ok(computer.run()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "\
+Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0
+";
+
+    #[test]
+    fn test_challenge1() {
+        let day = Day17 {};
+        assert_eq!(day.challenge1(INPUT).unwrap(), "4,6,3,5,6,3,5,2,1,0");
+    }
+
+    #[test]
+    fn test_large_exponent() {
+        let day = Day17 {};
+        let program = |a: u32, program: &str| {
+            let input = format!(
+                "Register A: {}\nRegister B: 7\nRegister C: 0\n\nProgram: {}\n",
+                a, program
+            );
+            day.challenge1(&input)
+        };
+        // A is divided by 2^32 and more, which leaves 0
+        assert_eq!(program(32, "0,4,5,4").unwrap(), "0");
+        assert_eq!(program(u32::MAX, "6,4,7,4,5,5,5,6").unwrap(), "0,0");
+        assert_eq!(program(31, "0,4,5,4").unwrap(), "0");
+        assert_eq!(program(64, "0,5,5,4").unwrap(), "0");
+        assert_eq!(
+            program(8, "0,7").unwrap_err().message,
+            "Invalid combo operand"
+        );
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let day = Day17 {};
+        let err = day.challenge1(&INPUT.replace("B: 0", "B: -1")).unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), Some(13)));
+        let err = day
+            .challenge1(&INPUT.replace("Register C: 0\n", ""))
+            .unwrap_err();
+        assert_eq!(err.message, "Missing `Register C`");
+    }
+}
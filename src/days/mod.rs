@@ -1,2 +1,38 @@
-pub mod day01;
-pub mod day02;
\ No newline at end of file
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+/// Registers the solution of a `days/dayNN.rs` module for the given day, optionally of
+/// another year than [`YEAR`](crate::puzzle::YEAR): `register_solution!(2023, 5, Day05 {})`.
+///
+/// The module is picked up by `AdventOfCode::new()` without any further changes.
+#[macro_export]
+macro_rules! register_solution {
+    ($day:expr, $solution:expr) => {
+        $crate::register_solution!($crate::puzzle::YEAR, $day, $solution);
+    };
+    ($year:expr, $day:expr, $solution:expr) => {
+        pub const PUZZLE: $crate::PuzzleId = $crate::PuzzleId {
+            year: $year,
+            day: $day,
+        };
+        const _: () = assert!(PUZZLE.is_valid(), "Puzzle is not part of any calendar");
+
+        pub fn solution() -> Box<dyn $crate::Solution> {
+            Box::new($solution)
+        }
+    };
+}
+
+/// Fails the build if two modules register a solution for the same puzzle.
+const fn assert_unique_days(puzzles: &[crate::PuzzleId]) {
+    let mut i = 0;
+    while i < puzzles.len() {
+        let mut j = i + 1;
+        while j < puzzles.len() {
+            if puzzles[i].year == puzzles[j].year && puzzles[i].day == puzzles[j].day {
+                panic!("Two modules in src/days register a solution for the same day");
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+}
@@ -0,0 +1,121 @@
+use crate::PuzzleId;
+use std::fmt;
+use std::time::Duration;
+
+pub type SolutionResult<T> = Result<T, SolutionError>;
+
+/// Error returned by a `Solution` part.
+///
+/// Solutions only know where in the input things went wrong, `Day::solve` fills in the
+/// puzzle and part afterwards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolutionError {
+    pub kind: ErrorKind,
+    pub puzzle: Option<PuzzleId>,
+    pub part: Option<u8>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The solution returned an error, e.g. for invalid input.
+    #[default]
+    Failed,
+    /// The solution panicked.
+    Panicked,
+    /// The solution did not finish within its time budget.
+    TimedOut,
+}
+
+impl SolutionError {
+    pub fn new(message: impl Into<String>) -> Self {
+        SolutionError {
+            kind: ErrorKind::Failed,
+            puzzle: None,
+            part: None,
+            line: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    /// Error at a 1-based line and column of the input.
+    pub fn at(line: usize, column: usize, message: impl Into<String>) -> Self {
+        SolutionError {
+            line: Some(line),
+            column: Some(column),
+            ..SolutionError::new(message)
+        }
+    }
+
+    /// Error for `token` in the 0-based line `index`, `token` has to be a sub slice of `line`.
+    pub fn in_line(index: usize, line: &str, token: &str, message: impl fmt::Display) -> Self {
+        let offset = (token.as_ptr() as usize).saturating_sub(line.as_ptr() as usize);
+        let column = line[..offset.min(line.len())].chars().count() + 1;
+        SolutionError::at(index + 1, column, format!("{} (`{}`)", message, token))
+    }
+
+    pub fn timed_out(limit: Duration) -> Self {
+        SolutionError {
+            kind: ErrorKind::TimedOut,
+            ..SolutionError::new(format!("Timed out after {:.2?}", limit))
+        }
+    }
+
+    pub fn panicked() -> Self {
+        SolutionError {
+            kind: ErrorKind::Panicked,
+            ..SolutionError::new("Solution panicked")
+        }
+    }
+
+    pub fn for_puzzle(mut self, puzzle: PuzzleId, part: u8) -> Self {
+        self.puzzle = Some(puzzle);
+        self.part = Some(part);
+        self
+    }
+}
+
+impl fmt::Display for SolutionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let (Some(puzzle), Some(part)) = (self.puzzle, self.part) {
+            write!(f, "{} part {}: ", puzzle, part)?;
+        }
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {}, column {}: ", line, column)?,
+            (Some(line), None) => write!(f, "line {}: ", line)?,
+            _ => {}
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for SolutionError {}
+
+/// Lets solutions built on `anyhow`, like the ones ported from `src/bin`, use `?`.
+impl From<anyhow::Error> for SolutionError {
+    fn from(err: anyhow::Error) -> Self {
+        SolutionError::new(format!("{:#}", err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_in_line() {
+        let line = "3   x4";
+        let token = line.split_whitespace().nth(1).unwrap();
+        let puzzle = PuzzleId::day(1).unwrap();
+        let err = SolutionError::in_line(2, line, token, "invalid number").for_puzzle(puzzle, 2);
+        assert_eq!(err.line, Some(3));
+        assert_eq!(err.column, Some(5));
+        assert_eq!(
+            err.to_string(),
+            "2024 day 01 part 2: line 3, column 5: invalid number (`x4`)"
+        );
+    }
+}
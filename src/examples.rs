@@ -0,0 +1,297 @@
+use crate::check::Parts;
+use crate::{puzzle, Answer, PuzzleId};
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Default location of the example fixtures, relative to the crate root.
+pub const FIXTURES_DIR: &str = "fixtures";
+
+/// Expected answers of the examples in a fixture directory.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// An example input of a puzzle description with the answers it gives.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    /// `example-N`, the file stem of the input.
+    pub name: String,
+    pub input: String,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+impl Example {
+    pub fn answer(&self, part: u8) -> Option<&Answer> {
+        match part {
+            1 => self.part1.as_ref(),
+            _ => self.part2.as_ref(),
+        }
+    }
+
+    fn answer_mut(&mut self, part: u8) -> &mut Option<Answer> {
+        match part {
+            1 => &mut self.part1,
+            _ => &mut self.part2,
+        }
+    }
+}
+
+/// Extracts the examples of a saved puzzle description page.
+///
+/// Every `<article>` describes one part. Its answer is the last emphasized code in it
+/// (`<code><em>143</em></code>`) and belongs to the last `<pre><code>` block before it, or
+/// to the first example of the page if the part has no block of its own ("in the example
+/// above"). Blocks without an answer are illustrations and are left out.
+pub fn extract(html: &str) -> Vec<Example> {
+    let article = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+    let token = Regex::new(
+        r"(?s)<pre><code>(?<block>.*?)</code></pre>|<code><em>(?<answer>.*?)</em></code>|<em><code>(?<em_answer>.*?)</code></em>",
+    )
+    .unwrap();
+
+    let mut articles: Vec<&str> = article
+        .captures_iter(html)
+        .map(|caps| caps.get(1).unwrap().as_str())
+        .collect();
+    if articles.is_empty() {
+        articles.push(html);
+    }
+
+    // Every block of the page, with the answers found for it
+    let mut blocks: Vec<Example> = Vec::new();
+    for (part, article) in (1..=2).zip(articles) {
+        let mut last_block = None;
+        let mut answer = None;
+        for caps in token.captures_iter(article) {
+            if let Some(block) = caps.name("block") {
+                blocks.push(Example {
+                    name: String::new(),
+                    input: text(block.as_str()),
+                    part1: None,
+                    part2: None,
+                });
+                last_block = Some(blocks.len() - 1);
+            } else if let Some(value) = caps.name("answer").or(caps.name("em_answer")) {
+                answer = Some((last_block, parse_answer(&text(value.as_str()))));
+            }
+        }
+
+        if let Some((block, answer)) = answer {
+            if let Some(example) = block.or((!blocks.is_empty()).then_some(0)) {
+                *blocks[example].answer_mut(part) = Some(answer);
+            }
+        }
+    }
+
+    blocks
+        .into_iter()
+        .filter(|example| example.part1.is_some() || example.part2.is_some())
+        .enumerate()
+        .map(|(index, example)| Example {
+            name: format!("example-{}", index + 1),
+            ..example
+        })
+        .collect()
+}
+
+/// Text of an HTML fragment: tags removed and entities decoded.
+fn text(html: &str) -> String {
+    static TAG: OnceLock<Regex> = OnceLock::new();
+    TAG.get_or_init(|| Regex::new(r"<[^>]*>").unwrap())
+        .replace_all(html, "")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+fn parse_answer(text: &str) -> Answer {
+    let text = text.trim();
+    if let Ok(value) = text.parse::<u64>() {
+        Answer::U64(value)
+    } else if let Ok(value) = text.parse::<i64>() {
+        Answer::I64(value)
+    } else {
+        Answer::from(text)
+    }
+}
+
+/// Fixture directory of `id` below `root`: `NN` for the default event, `YYYY/NN` otherwise.
+pub fn fixture_dir(root: &Path, id: PuzzleId) -> PathBuf {
+    if id.year == puzzle::YEAR {
+        root.join(format!("{:0>2}", id.day))
+    } else {
+        root.join(format!("{}/{:0>2}", id.year, id.day))
+    }
+}
+
+fn load_answers(dir: &Path) -> io::Result<BTreeMap<String, Parts>> {
+    let path = dir.join(ANSWERS_FILE);
+    match std::fs::read_to_string(&path) {
+        Ok(text) => toml::from_str(&text)
+            .map_err(|err| io::Error::other(format!("{}: {}", path.display(), err))),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+        Err(err) => Err(err),
+    }
+}
+
+/// Reads the examples of `id`: every `example-*.txt` of its fixture directory with the
+/// answers listed for it in `answers.toml`. A missing directory has no examples.
+pub fn load(root: &Path, id: PuzzleId) -> io::Result<Vec<Example>> {
+    let dir = fixture_dir(root, id);
+    let entries = match std::fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+    let mut answers = load_answers(&dir)?;
+
+    let mut examples = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        let Some(name) = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_suffix(".txt"))
+            .filter(|name| name.starts_with("example-"))
+        else {
+            continue;
+        };
+        let parts = answers.remove(name).unwrap_or_default();
+        examples.push(Example {
+            name: name.to_string(),
+            input: std::fs::read_to_string(&path)?,
+            part1: parts.part1,
+            part2: parts.part2,
+        });
+    }
+    examples.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(examples)
+}
+
+/// Writes `examples` into the fixture directory of `id` and returns the directory.
+///
+/// Inputs of the same name are replaced. Answers are merged into `answers.toml`, so a page
+/// saved after solving part 1 adds the answer of part 2, and examples added by hand stay.
+pub fn save(root: &Path, id: PuzzleId, examples: &[Example]) -> io::Result<PathBuf> {
+    let dir = fixture_dir(root, id);
+    std::fs::create_dir_all(&dir)?;
+    let mut answers = load_answers(&dir)?;
+
+    for example in examples {
+        std::fs::write(dir.join(format!("{}.txt", example.name)), &example.input)?;
+        let parts = answers.entry(example.name.clone()).or_default();
+        if example.part1.is_some() {
+            parts.part1 = example.part1.clone();
+        }
+        if example.part2.is_some() {
+            parts.part2 = example.part2.clone();
+        }
+    }
+
+    let answers = toml::to_string(&answers).map_err(io::Error::other)?;
+    std::fs::write(dir.join(ANSWERS_FILE), answers)?;
+    Ok(dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 3: Mull It Over ---</h2>
+<p>For example, consider the following section of corrupted memory:</p>
+<pre><code>x<em>mul(2,4)</em>%&amp;mul[3,7]!@^do_not_<em>mul(5,5)</em>
+</code></pre>
+<p>Adding up the result of each instruction produces <code><em>161</em></code> (<code>2*4 + 5*5</code>).</p>
+</article>
+<p>Your puzzle answer was <code>167090022</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>This time, the corrupted memory looks like this:</p>
+<pre><code>xmul(2,4)&amp;mul[3,7]!^don't()_mul(5,5)
+</code></pre>
+<p>This time, the sum of the results is <code><em>48</em></code>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_extract() {
+        let examples = extract(PAGE);
+        assert_eq!(
+            examples,
+            vec![
+                Example {
+                    name: "example-1".to_string(),
+                    input: "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)\n".to_string(),
+                    part1: Some(Answer::U64(161)),
+                    part2: None,
+                },
+                Example {
+                    name: "example-2".to_string(),
+                    input: "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)\n".to_string(),
+                    part1: None,
+                    part2: Some(Answer::U64(48)),
+                },
+            ]
+        );
+
+        // Part two refers to the example above, illustrations without an answer are skipped
+        let page = r#"<article><pre><code>3   4
+4   3
+</code></pre><p>A total of <code><em>11</em></code>!</p><pre><code>4 3</code></pre></article>
+<article><p>The similarity score is <em><code>31</code></em>.</p></article>"#;
+        let examples = extract(page);
+        assert_eq!(examples.len(), 1);
+        assert_eq!(examples[0].input, "3   4\n4   3\n");
+        assert_eq!(examples[0].part1, Some(Answer::U64(11)));
+        assert_eq!(examples[0].part2, Some(Answer::U64(31)));
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let root = std::env::temp_dir().join(format!("aoc-fixtures-{}", std::process::id()));
+        let id = PuzzleId::new(2023, 3).unwrap();
+
+        let mut examples = extract(PAGE);
+        examples[1].part2 = None;
+        save(&root, id, &examples).unwrap();
+        examples[1].part2 = Some(Answer::from("48"));
+        examples[0].part1 = None;
+        let dir = save(&root, id, &examples).unwrap();
+        assert_eq!(dir, root.join("2023/03"));
+
+        let loaded = load(&root, id).unwrap();
+        assert_eq!(loaded.len(), 2);
+        assert_eq!(loaded[0].input, examples[0].input);
+        assert_eq!(loaded[0].answer(1), Some(&Answer::U64(161)));
+        assert_eq!(loaded[1].answer(2), Some(&Answer::U64(48)));
+        assert!(load(&root, PuzzleId::day(3).unwrap()).unwrap().is_empty());
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    /// Solves `part` of the fixture `name` with the registered solution of `year`/`day`.
+    fn check_fixture(year: u32, day: u32, part: u8, name: &str) {
+        let id = PuzzleId::new(year, day).unwrap();
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join(FIXTURES_DIR);
+        let example = load(&root, id)
+            .unwrap()
+            .into_iter()
+            .find(|example| example.name == name)
+            .unwrap();
+        let (_, solution) = crate::days::registry()
+            .into_iter()
+            .find(|(registered, _)| *registered == id)
+            .unwrap_or_else(|| panic!("{} has no registered solution", id));
+
+        let answer = crate::challenge(&*solution, &example.input, part);
+        assert_eq!(answer.as_ref().ok(), example.answer(part), "{:?}", answer);
+    }
+
+    // One test per example and part in `fixtures/`, generated by the build script
+    include!(concat!(env!("OUT_DIR"), "/fixtures.rs"));
+}
@@ -0,0 +1,178 @@
+use crate::client::{missing_session, HttpClient};
+use crate::input::DirectoryInput;
+use crate::PuzzleId;
+use std::io;
+use std::path::PathBuf;
+
+/// Outcome of `InputFetcher::fetch`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already in the input directory, nothing was downloaded.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Downloads puzzle inputs into an input directory.
+///
+/// Inputs which are already there are never downloaded again. Empty files, as left by
+/// `new-day`, don't count as cached.
+pub struct InputFetcher {
+    client: Box<dyn HttpClient>,
+    base_url: String,
+    session: Option<String>,
+    pub inputs: DirectoryInput,
+}
+
+impl InputFetcher {
+    pub fn new(
+        client: Box<dyn HttpClient>,
+        base_url: impl Into<String>,
+        session: Option<String>,
+        inputs: DirectoryInput,
+    ) -> Self {
+        InputFetcher {
+            client,
+            base_url: base_url.into(),
+            session,
+            inputs,
+        }
+    }
+
+    pub fn url(&self, id: PuzzleId) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, id.year, id.day)
+    }
+
+    /// Stores the input of `id` in the input directory, downloading it if it is missing.
+    pub fn fetch(&self, id: PuzzleId) -> io::Result<Fetched> {
+        let path = self.inputs.path(id);
+        if std::fs::metadata(&path).is_ok_and(|meta| meta.len() > 0) {
+            return Ok(Fetched::Cached(path));
+        }
+
+        let session = self.session.as_deref().ok_or_else(missing_session)?;
+        let url = self.url(id);
+        let response = self
+            .client
+            .get(&url, &[("Cookie", &format!("session={}", session))])?;
+        if response.status != 200 {
+            return Err(io::Error::other(format!(
+                "{} responded with status {}: {}",
+                url,
+                response.status,
+                response.body.lines().next().unwrap_or_default().trim()
+            )));
+        }
+
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        // Written next to the input first, so an interrupted download is never cached
+        let partial = path.with_extension("txt.part");
+        std::fs::write(&partial, &response.body)?;
+        std::fs::rename(&partial, &path)?;
+        Ok(Fetched::Downloaded(path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{Response, UreqClient};
+    use std::sync::{Arc, Mutex};
+
+    /// Answers every request with the same response and records the requested URLs.
+    struct FakeClient {
+        response: Response,
+        requests: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl HttpClient for FakeClient {
+        fn get(&self, url: &str, headers: &[(&str, &str)]) -> io::Result<Response> {
+            assert_eq!(headers, [("Cookie", "session=token")]);
+            self.requests.lock().unwrap().push(url.to_string());
+            Ok(self.response.clone())
+        }
+
+        fn post(&self, _: &str, _: &[(&str, &str)], _: &[(&str, &str)]) -> io::Result<Response> {
+            unreachable!("Inputs are only downloaded")
+        }
+    }
+
+    fn fetcher(name: &str, status: u16, body: &str) -> (InputFetcher, Arc<Mutex<Vec<String>>>) {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let client = FakeClient {
+            response: Response {
+                status,
+                body: body.to_string(),
+            },
+            requests: requests.clone(),
+        };
+        let fetcher = InputFetcher::new(
+            Box::new(client),
+            "http://aoc.test",
+            Some("token".to_string()),
+            DirectoryInput::new(dir),
+        );
+        (fetcher, requests)
+    }
+
+    #[test]
+    fn test_fetch_caches() {
+        let (fetcher, requests) = fetcher("cache", 200, "3   4\n");
+        let id = PuzzleId::new(2023, 1).unwrap();
+        let path = fetcher.inputs.path(id);
+
+        assert_eq!(
+            fetcher.fetch(id).unwrap(),
+            Fetched::Downloaded(path.clone())
+        );
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "3   4\n");
+        assert_eq!(fetcher.fetch(id).unwrap(), Fetched::Cached(path.clone()));
+        assert_eq!(
+            *requests.lock().unwrap(),
+            ["http://aoc.test/2023/day/1/input"]
+        );
+
+        std::fs::remove_dir_all(&fetcher.inputs.root).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let (fetcher, _) = fetcher("errors", 400, "Please log in\n");
+        let id = PuzzleId::day(2).unwrap();
+        let err = fetcher.fetch(id).unwrap_err();
+        assert!(err.to_string().contains("status 400: Please log in"));
+        assert!(!fetcher.inputs.path(id).exists());
+
+        let fetcher = InputFetcher {
+            session: None,
+            ..fetcher
+        };
+        let err = fetcher.fetch(id).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+    }
+
+    #[test]
+    fn test_fetch_stub_server() {
+        let (url, server) = crate::client::tests::stub_server("200 OK", "#####\n");
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-stub-{}", std::process::id()));
+        let fetcher = InputFetcher::new(
+            Box::new(UreqClient::default()),
+            url,
+            Some("token".to_string()),
+            DirectoryInput::new(&dir),
+        );
+
+        let id = PuzzleId::day(25).unwrap();
+        assert!(matches!(fetcher.fetch(id), Ok(Fetched::Downloaded(_))));
+        assert_eq!(
+            std::fs::read_to_string(dir.join("25.txt")).unwrap(),
+            "#####\n"
+        );
+        assert!(server.join().unwrap().starts_with("GET /2024/day/25/input"));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,350 @@
+use crate::grid::{Offset, Position};
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// Point on the plane of a puzzle, `x` grows to the right and `y` downwards like the rows
+/// of the input.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// Difference between two points.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vector {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    pub fn manhattan(self, other: Point) -> u64 {
+        (other - self).manhattan()
+    }
+
+    /// Grid position of the point, `None` for negative coordinates.
+    pub fn position(self) -> Option<Position> {
+        Some((usize::try_from(self.y).ok()?, usize::try_from(self.x).ok()?))
+    }
+}
+
+impl Vector {
+    pub const fn new(x: i64, y: i64) -> Self {
+        Vector { x, y }
+    }
+
+    /// Length in steps along the axes.
+    pub fn manhattan(self) -> u64 {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+
+    /// The vector as a grid offset of `(rows, columns)`.
+    pub fn offset(self) -> Offset {
+        (self.y as isize, self.x as isize)
+    }
+}
+
+impl From<Position> for Point {
+    fn from((row, column): Position) -> Self {
+        Point::new(column as i64, row as i64)
+    }
+}
+
+impl From<Offset> for Vector {
+    fn from((rows, columns): Offset) -> Self {
+        Vector::new(columns as i64, rows as i64)
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, v: Vector) -> Point {
+        Point::new(self.x + v.x, self.y + v.y)
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, v: Vector) {
+        *self = *self + v;
+    }
+}
+
+impl Sub<Vector> for Point {
+    type Output = Point;
+
+    fn sub(self, v: Vector) -> Point {
+        Point::new(self.x - v.x, self.y - v.y)
+    }
+}
+
+impl SubAssign<Vector> for Point {
+    fn sub_assign(&mut self, v: Vector) {
+        *self = *self - v;
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, other: Point) -> Vector {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        Vector::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, other: Vector) -> Vector {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<i64> for Vector {
+    type Output = Vector;
+
+    fn mul(self, factor: i64) -> Vector {
+        Vector::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::new(-self.x, -self.y)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// One of the 4 orthogonal directions, in clockwise order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction4 {
+    pub const ALL: [Direction4; 4] = [
+        Direction4::Up,
+        Direction4::Right,
+        Direction4::Down,
+        Direction4::Left,
+    ];
+
+    /// Turns by 90 degrees clockwise.
+    pub fn turn_right(self) -> Direction4 {
+        Direction4::ALL[(self as usize + 1) % 4]
+    }
+
+    /// Turns by 90 degrees counterclockwise.
+    pub fn turn_left(self) -> Direction4 {
+        Direction4::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Direction4 {
+        Direction4::ALL[(self as usize + 2) % 4]
+    }
+
+    /// A step into the direction.
+    pub fn vector(self) -> Vector {
+        match self {
+            Direction4::Up => Vector::new(0, -1),
+            Direction4::Right => Vector::new(1, 0),
+            Direction4::Down => Vector::new(0, 1),
+            Direction4::Left => Vector::new(-1, 0),
+        }
+    }
+
+    /// A step into the direction as a grid offset.
+    pub fn offset(self) -> Offset {
+        self.vector().offset()
+    }
+
+    /// The arrow drawn for the direction: `^`, `>`, `v` or `<`.
+    pub fn glyph(self) -> char {
+        match self {
+            Direction4::Up => '^',
+            Direction4::Right => '>',
+            Direction4::Down => 'v',
+            Direction4::Left => '<',
+        }
+    }
+
+    pub fn from_glyph(glyph: char) -> Option<Direction4> {
+        Direction4::ALL
+            .into_iter()
+            .find(|direction| direction.glyph() == glyph)
+    }
+}
+
+/// One of the 8 directions to the neighbors of a cell, in clockwise order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// Turns by 90 degrees clockwise, like `Direction4::turn_right`.
+    pub fn turn_right(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 2) % 8]
+    }
+
+    /// Turns by 90 degrees counterclockwise.
+    pub fn turn_left(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 6) % 8]
+    }
+
+    pub fn opposite(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    /// A step into the direction, diagonals move along both axes.
+    pub fn vector(self) -> Vector {
+        match self {
+            Direction8::Up => Vector::new(0, -1),
+            Direction8::UpRight => Vector::new(1, -1),
+            Direction8::Right => Vector::new(1, 0),
+            Direction8::DownRight => Vector::new(1, 1),
+            Direction8::Down => Vector::new(0, 1),
+            Direction8::DownLeft => Vector::new(-1, 1),
+            Direction8::Left => Vector::new(-1, 0),
+            Direction8::UpLeft => Vector::new(-1, -1),
+        }
+    }
+
+    /// A step into the direction as a grid offset.
+    pub fn offset(self) -> Offset {
+        self.vector().offset()
+    }
+
+    /// The arrow of an orthogonal direction, `None` for diagonals.
+    pub fn glyph(self) -> Option<char> {
+        Direction4::try_from(self).ok().map(Direction4::glyph)
+    }
+
+    pub fn from_glyph(glyph: char) -> Option<Direction8> {
+        Direction4::from_glyph(glyph).map(Direction8::from)
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        Direction8::ALL[direction as usize * 2]
+    }
+}
+
+impl TryFrom<Direction8> for Direction4 {
+    type Error = Direction8;
+
+    /// Fails for diagonals.
+    fn try_from(direction: Direction8) -> Result<Self, Direction8> {
+        match direction.is_diagonal() {
+            false => Ok(Direction4::ALL[direction as usize / 2]),
+            true => Err(direction),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point::new(1, 2);
+        let b = Point::new(4, -2);
+        assert_eq!(b - a, Vector::new(3, -4));
+        assert_eq!(a + (b - a), b);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(b.manhattan(a), 7);
+        assert_eq!(-Vector::new(1, -2) * 3, Vector::new(-3, 6));
+
+        let mut p = Point::ORIGIN;
+        p += Direction4::Down.vector();
+        p -= Direction4::Left.vector() * 2;
+        assert_eq!(p, Point::new(2, 1));
+        assert_eq!(p.position(), Some((1, 2)));
+        assert_eq!(Point::from((1, 2)), p);
+        assert_eq!(Point::new(-1, 0).position(), None);
+        assert_eq!(Vector::from((1, -1)).offset(), (1, -1));
+    }
+
+    #[test]
+    fn test_direction4() {
+        let up = Direction4::Up;
+        assert_eq!(up.turn_right(), Direction4::Right);
+        assert_eq!(up.turn_left(), Direction4::Left);
+        assert_eq!(up.opposite(), Direction4::Down);
+        assert_eq!(up.offset(), (-1, 0));
+        for direction in Direction4::ALL {
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(
+                direction.vector() + direction.opposite().vector(),
+                Vector::default()
+            );
+            assert_eq!(Direction4::from_glyph(direction.glyph()), Some(direction));
+        }
+        assert_eq!(Direction4::from_glyph('x'), None);
+    }
+
+    #[test]
+    fn test_direction8() {
+        let up_right = Direction8::UpRight;
+        assert_eq!(up_right.turn_right(), Direction8::DownRight);
+        assert_eq!(up_right.turn_left(), Direction8::UpLeft);
+        assert_eq!(up_right.opposite(), Direction8::DownLeft);
+        assert_eq!(up_right.offset(), (-1, 1));
+        assert_eq!(up_right.glyph(), None);
+        assert_eq!(Direction4::try_from(up_right), Err(up_right));
+        for direction in Direction4::ALL {
+            let direction8 = Direction8::from(direction);
+            assert_eq!(direction8.vector(), direction.vector());
+            assert_eq!(Direction4::try_from(direction8), Ok(direction));
+            assert_eq!(Direction8::from_glyph(direction.glyph()), Some(direction8));
+        }
+    }
+}
@@ -0,0 +1,364 @@
+use crate::geometry::{Direction4, Direction8, Point, Vector};
+use crate::{SolutionError, SolutionResult};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// Position of a cell as `(row, column)`.
+pub type Position = (usize, usize);
+
+/// Offset between two cells as `(rows, columns)`, rows grow downwards.
+pub type Offset = (isize, isize);
+
+/// Rectangular grid of cells, stored row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Grid of `width` x `height` cells set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Grid of rows `width` cells long, `cells` has to hold a whole number of rows.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            cells.len().is_multiple_of(width),
+            "{} cells don't make rows of {}",
+            cells.len(),
+            width
+        );
+        Grid {
+            width,
+            height: cells.len().checked_div(width).unwrap_or(0),
+            cells,
+        }
+    }
+
+    /// Parses one row per line of `input`, mapping every character with `cell`.
+    ///
+    /// Characters `cell` returns `None` for and rows shorter or longer than the first one are
+    /// reported with their line and column.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> SolutionResult<Self> {
+        let mut width = None;
+        let mut cells = Vec::with_capacity(input.len());
+        let mut height = 0;
+        for (index, line) in input.lines().enumerate() {
+            let start = cells.len();
+            for (column, c) in line.chars().enumerate() {
+                let value = cell(c).ok_or_else(|| {
+                    SolutionError::at(index + 1, column + 1, format!("Unexpected `{}`", c))
+                })?;
+                cells.push(value);
+            }
+
+            let length = cells.len() - start;
+            let expected = *width.get_or_insert(length);
+            if length != expected {
+                return Err(SolutionError::at(
+                    index + 1,
+                    length.min(expected) + 1,
+                    format!("Row has {} cells, expected {}", length, expected),
+                ));
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (row, column): Position) -> Option<&T> {
+        self.index_of(row, column).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, (row, column): Position) -> Option<&mut T> {
+        self.index_of(row, column)
+            .map(|index| &mut self.cells[index])
+    }
+
+    fn index_of(&self, row: usize, column: usize) -> Option<usize> {
+        (row < self.height && column < self.width).then(|| row * self.width + column)
+    }
+
+    /// Position `offset` away from `position`, `None` if that is outside of the grid.
+    pub fn offset(&self, (row, column): Position, (rows, columns): Offset) -> Option<Position> {
+        let row = row.checked_add_signed(rows)?;
+        let column = column.checked_add_signed(columns)?;
+        self.index_of(row, column).map(|_| (row, column))
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point
+            .position()
+            .is_some_and(|(row, column)| self.index_of(row, column).is_some())
+    }
+
+    /// `point` moved by `step`, `None` if that leaves the grid.
+    pub fn step(&self, point: Point, step: Vector) -> Option<Point> {
+        Some(point + step).filter(|&next| self.contains(next))
+    }
+
+    /// Positions of the orthogonal neighbors of `position` inside the grid, clockwise
+    /// starting upwards.
+    pub fn neighbors4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction4::ALL
+            .into_iter()
+            .filter_map(move |direction| self.offset(position, direction.offset()))
+    }
+
+    /// Positions of all 8 neighbors of `position` inside the grid, diagonals included.
+    pub fn neighbors8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |direction| self.offset(position, direction.offset()))
+    }
+
+    /// Cells from `start` on in steps of `step` until the edge of the grid, e.g. a diagonal
+    /// with `(1, 1)`. Empty if `start` is outside of the grid.
+    pub fn ray(&self, start: Position, step: Offset) -> impl Iterator<Item = (Position, &T)> {
+        let first = self.get(start).map(|_| start);
+        std::iter::successors(first, move |&position| self.offset(position, step))
+            .map(|position| (position, &self[position]))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` doesn't accept a size of 0, which an empty grid would have
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            column < self.width,
+            "Column {} is outside of the grid",
+            column
+        );
+        self.cells.iter().skip(column).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|column| self.column(column))
+    }
+
+    /// Diagonals running down and to the right, starting at the bottom left corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.height)
+            .rev()
+            .map(|row| (row, 0))
+            .chain((1..self.width).map(|column| (0, column)));
+        starts.map(|start| self.ray(start, (1, 1)).map(|(_, cell)| cell))
+    }
+
+    /// Diagonals running down and to the left, starting at the top left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let last = self.width.saturating_sub(1);
+        let starts = (0..self.width)
+            .map(|column| (0, column))
+            .chain((1..self.height).map(move |row| (row, last)));
+        starts.map(|start| self.ray(start, (1, -1)).map(|(_, cell)| cell))
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |index| (index / width, index % width))
+    }
+
+    /// All cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Position of the first cell, row by row, matching `predicate`.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    /// Position of the first cell equal to `value`.
+    pub fn find(&self, value: &T) -> Option<Position>
+    where
+        T: PartialEq,
+    {
+        self.position(|cell| cell == value)
+    }
+
+    /// Grid of the same size with every cell mapped by `f`.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, column): Position) -> &T {
+        match self.index_of(row, column) {
+            Some(index) => &self.cells[index],
+            None => panic!("({}, {}) is outside of the grid", row, column),
+        }
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, (row, column): Position) -> &mut T {
+        match self.index_of(row, column) {
+            Some(index) => &mut self.cells[index],
+            None => panic!("({}, {}) is outside of the grid", row, column),
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        match point.position() {
+            Some(position) => &self[position],
+            None => panic!("{} is outside of the grid", point),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        match point.position() {
+            Some(position) => &mut self[position],
+            None => panic!("{} is outside of the grid", point),
+        }
+    }
+}
+
+impl std::str::FromStr for Grid<char> {
+    type Err = SolutionError;
+
+    fn from_str(input: &str) -> SolutionResult<Self> {
+        Grid::parse(input, Some)
+    }
+}
+
+/// Prints the grid row by row, with every row on a line of its own.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "\
+abc
+def
+";
+
+    #[test]
+    fn test_parse() {
+        let grid: Grid<char> = INPUT.parse().unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 2)], 'f');
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.to_string(), INPUT);
+
+        let digits = Grid::parse("12\n34\n", |c| c.to_digit(10)).unwrap();
+        assert_eq!(digits.row(1), &[3, 4]);
+
+        let err = Grid::parse("12\n3x\n", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), Some(2)));
+        assert_eq!(err.message, "Unexpected `x`");
+        let err = "abc\nde\n".parse::<Grid<char>>().unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), Some(3)));
+        assert_eq!(err.message, "Row has 2 cells, expected 3");
+
+        let empty: Grid<char> = "".parse().unwrap();
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+        assert_eq!(empty.rows().count(), 0);
+        assert_eq!(empty.to_string(), "");
+    }
+
+    #[test]
+    fn test_access() {
+        let mut grid: Grid<char> = INPUT.parse().unwrap();
+        assert_eq!(grid.offset((0, 0), (1, 2)), Some((1, 2)));
+        assert_eq!(grid.offset((0, 0), (-1, 0)), None);
+        assert_eq!(grid.offset((1, 2), (0, 1)), None);
+        let corner = Point::new(2, 1);
+        assert!(grid.contains(corner) && !grid.contains(Point::new(3, 1)));
+        assert_eq!(grid[corner], 'f');
+        assert_eq!(grid.step(corner, Vector::new(-2, -1)), Some(Point::ORIGIN));
+        assert_eq!(grid.step(corner, Direction4::Down.vector()), None);
+
+        *grid.get_mut((0, 1)).unwrap() = 'x';
+        grid[(1, 1)] = 'y';
+        assert_eq!(grid.to_string(), "axc\ndyf\n");
+        assert_eq!(grid.find(&'y'), Some((1, 1)));
+        assert_eq!(grid.position(|c| c.is_uppercase()), None);
+        assert_eq!(
+            grid.map(|c| c.to_ascii_uppercase()).to_string(),
+            "AXC\nDYF\n"
+        );
+
+        let new = Grid::new(2, 1, 0);
+        assert_eq!(new, Grid::from_cells(2, vec![0, 0]));
+    }
+
+    #[test]
+    fn test_iterators() {
+        let grid: Grid<char> = INPUT.parse().unwrap();
+        let text = |cells: &mut dyn Iterator<Item = &char>| cells.collect::<String>();
+
+        assert_eq!(grid.rows().count(), 2);
+        let columns: Vec<String> = grid.columns().map(|mut c| text(&mut c)).collect();
+        assert_eq!(columns, vec!["ad", "be", "cf"]);
+        let diagonals: Vec<String> = grid.diagonals().map(|mut d| text(&mut d)).collect();
+        assert_eq!(diagonals, vec!["d", "ae", "bf", "c"]);
+        let anti: Vec<String> = grid.anti_diagonals().map(|mut d| text(&mut d)).collect();
+        assert_eq!(anti, vec!["a", "bd", "ce", "f"]);
+
+        let ray: Vec<_> = grid.ray((0, 2), (1, -1)).collect();
+        assert_eq!(ray, vec![((0, 2), &'c'), ((1, 1), &'e')]);
+        assert_eq!(grid.ray((2, 0), (0, 1)).count(), 0);
+
+        let corner: Vec<_> = grid.neighbors4((0, 0)).collect();
+        assert_eq!(corner, vec![(0, 1), (1, 0)]);
+        let middle: Vec<_> = grid.neighbors8((1, 1)).collect();
+        assert_eq!(middle, vec![(0, 1), (0, 2), (1, 2), (1, 0), (0, 0)]);
+        assert_eq!(grid.positions().last(), Some((1, 2)));
+    }
+}
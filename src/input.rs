@@ -0,0 +1,263 @@
+use crate::{puzzle, PuzzleId};
+use flate2::read::GzDecoder;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Environment variable overriding the input directory.
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+/// Optional config file, looked up in the working directory and the crate root.
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/// Source of puzzle inputs.
+pub trait InputProvider: Send + Sync {
+    /// Reads the input of `id`, a missing input is reported as `io::ErrorKind::NotFound`.
+    fn load(&self, id: PuzzleId) -> io::Result<String>;
+
+    /// Where the input of `id` is read from, used in error messages.
+    fn describe(&self, id: PuzzleId) -> String;
+}
+
+/// Location of an input relative to an input directory or archive: `NN.txt` for the
+/// default event, `YYYY/NN.txt` for every other one.
+pub fn relative_path(id: PuzzleId) -> PathBuf {
+    if id.year == puzzle::YEAR {
+        PathBuf::from(format!("{:0>2}.txt", id.day))
+    } else {
+        PathBuf::from(format!("{}/{:0>2}.txt", id.year, id.day))
+    }
+}
+
+/// Inputs stored as text files in a directory.
+pub struct DirectoryInput {
+    pub root: PathBuf,
+}
+
+/// Settings of `aoc.toml`, relative paths are relative to the file.
+#[derive(Default, Deserialize)]
+pub(crate) struct Config {
+    pub input_dir: Option<PathBuf>,
+    pub base_url: Option<String>,
+    pub session_file: Option<PathBuf>,
+}
+
+impl DirectoryInput {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        DirectoryInput { root: root.into() }
+    }
+
+    /// Resolves the input directory from, in order: `dir`, the `AOC_INPUT_DIR` environment
+    /// variable, `input_dir` in `aoc.toml`, and the crate's own `input` directory.
+    pub fn locate(dir: Option<&Path>) -> Self {
+        if let Some(dir) = dir {
+            return DirectoryInput::new(dir);
+        }
+        if let Some(dir) = std::env::var_os(INPUT_DIR_ENV) {
+            return DirectoryInput::new(dir);
+        }
+
+        for config_dir in config_dirs() {
+            if let Some(dir) = read_config(config_dir).and_then(|config| config.input_dir) {
+                return DirectoryInput::new(config_dir.join(dir));
+            }
+        }
+        DirectoryInput::new(Path::new(env!("CARGO_MANIFEST_DIR")).join("input"))
+    }
+
+    pub fn path(&self, id: PuzzleId) -> PathBuf {
+        self.root.join(relative_path(id))
+    }
+}
+
+/// Directories `aoc.toml` is looked up in, in order: the working directory and the crate root.
+pub(crate) fn config_dirs() -> [&'static Path; 2] {
+    [Path::new("."), Path::new(env!("CARGO_MANIFEST_DIR"))]
+}
+
+pub(crate) fn read_config(dir: &Path) -> Option<Config> {
+    let config = std::fs::read_to_string(dir.join(CONFIG_FILE)).ok()?;
+    match toml::from_str::<Config>(&config) {
+        Ok(config) => Some(config),
+        Err(err) => {
+            eprintln!("Ignoring {}: {}", dir.join(CONFIG_FILE).display(), err);
+            None
+        }
+    }
+}
+
+impl InputProvider for DirectoryInput {
+    fn load(&self, id: PuzzleId) -> io::Result<String> {
+        std::fs::read_to_string(self.path(id))
+    }
+
+    fn describe(&self, id: PuzzleId) -> String {
+        self.path(id).display().to_string()
+    }
+}
+
+/// Standard input, read once on first use and handed to every puzzle.
+#[derive(Default)]
+pub struct StdinInput {
+    input: OnceLock<Result<String, String>>,
+}
+
+impl InputProvider for StdinInput {
+    fn load(&self, _id: PuzzleId) -> io::Result<String> {
+        self.input
+            .get_or_init(|| {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map(|_| input)
+                    .map_err(|err| err.to_string())
+            })
+            .clone()
+            .map_err(io::Error::other)
+    }
+
+    fn describe(&self, _id: PuzzleId) -> String {
+        "<stdin>".to_string()
+    }
+}
+
+/// A fixed input handed to every puzzle.
+pub struct StringInput(pub String);
+
+impl InputProvider for StringInput {
+    fn load(&self, _id: PuzzleId) -> io::Result<String> {
+        Ok(self.0.clone())
+    }
+
+    fn describe(&self, _id: PuzzleId) -> String {
+        "<memory>".to_string()
+    }
+}
+
+/// Inputs packed into a gzip compressed tar archive, laid out like an input directory.
+/// The archive is unpacked into memory on first use.
+pub struct ArchiveInput {
+    pub path: PathBuf,
+    entries: OnceLock<Result<HashMap<PathBuf, String>, String>>,
+}
+
+impl ArchiveInput {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        ArchiveInput {
+            path: path.into(),
+            entries: OnceLock::new(),
+        }
+    }
+
+    fn unpack(&self) -> io::Result<HashMap<PathBuf, String>> {
+        let mut archive = tar::Archive::new(GzDecoder::new(File::open(&self.path)?));
+        let mut entries = HashMap::new();
+        for entry in archive.entries()? {
+            let mut entry = entry?;
+            if !entry.header().entry_type().is_file() {
+                continue;
+            }
+            let path: PathBuf = entry
+                .path()?
+                .components()
+                .filter(|c| !matches!(c, std::path::Component::CurDir))
+                .collect();
+            let mut content = String::new();
+            entry.read_to_string(&mut content)?;
+            entries.insert(path, content);
+        }
+        Ok(entries)
+    }
+}
+
+impl InputProvider for ArchiveInput {
+    fn load(&self, id: PuzzleId) -> io::Result<String> {
+        let entries = self
+            .entries
+            .get_or_init(|| self.unpack().map_err(|err| err.to_string()))
+            .as_ref()
+            .map_err(|err| io::Error::other(err.clone()))?;
+
+        let path = relative_path(id);
+        entries
+            .get(&path)
+            .or_else(|| entries.get(&Path::new("input").join(&path)))
+            .cloned()
+            .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))
+    }
+
+    fn describe(&self, id: PuzzleId) -> String {
+        format!("{}:{}", self.path.display(), relative_path(id).display())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-input-{}-{}", name, std::process::id()));
+        std::fs::create_dir_all(dir.join("2023")).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_directory_input() {
+        let dir = temp_dir("dir");
+        std::fs::write(dir.join("05.txt"), "2024").unwrap();
+        std::fs::write(dir.join("2023/05.txt"), "2023").unwrap();
+
+        let provider = DirectoryInput::locate(Some(&dir));
+        assert_eq!(provider.load(PuzzleId::day(5).unwrap()).unwrap(), "2024");
+        assert_eq!(
+            provider.load(PuzzleId::new(2023, 5).unwrap()).unwrap(),
+            "2023"
+        );
+        let missing = provider.load(PuzzleId::day(6).unwrap()).unwrap_err();
+        assert_eq!(missing.kind(), io::ErrorKind::NotFound);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_archive_input() {
+        let dir = temp_dir("archive");
+        let path = dir.join("inputs.tar.gz");
+
+        let mut builder = tar::Builder::new(GzEncoder::new(
+            File::create(&path).unwrap(),
+            Compression::default(),
+        ));
+        for (name, content) in [("input/01.txt", "3   4\n"), ("2023/25.txt", "#####\n")] {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(content.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder
+                .append_data(&mut header, name, content.as_bytes())
+                .unwrap();
+        }
+        builder.into_inner().unwrap().finish().unwrap();
+
+        let provider = ArchiveInput::new(&path);
+        assert_eq!(provider.load(PuzzleId::day(1).unwrap()).unwrap(), "3   4\n");
+        assert_eq!(
+            provider.load(PuzzleId::new(2023, 25).unwrap()).unwrap(),
+            "#####\n"
+        );
+        assert!(provider.load(PuzzleId::day(2).unwrap()).is_err());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_string_input() {
+        let provider = StringInput("1 2 3".to_string());
+        assert_eq!(provider.load(PuzzleId::day(17).unwrap()).unwrap(), "1 2 3");
+    }
+}
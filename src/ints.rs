@@ -0,0 +1,228 @@
+use crate::{SolutionError, SolutionResult};
+use std::marker::PhantomData;
+
+/// Primitive integers the scanner can read.
+pub trait Int: Copy + Default {
+    const SIGNED: bool;
+
+    /// `self * 10 + digit`, or minus `digit` while reading a negative number. `None` on
+    /// overflow.
+    fn push_digit(self, digit: u8, negative: bool) -> Option<Self>;
+}
+
+macro_rules! impl_int {
+    ($signed:literal: $($int:ty),+) => {
+        $(
+            impl Int for $int {
+                const SIGNED: bool = $signed;
+
+                #[inline]
+                fn push_digit(self, digit: u8, negative: bool) -> Option<Self> {
+                    let value = self.checked_mul(10)?;
+                    match negative {
+                        false => value.checked_add(digit as $int),
+                        true => value.checked_sub(digit as $int),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_int!(false: u8, u16, u32, u64, u128, usize);
+impl_int!(true: i8, i16, i32, i64, i128, isize);
+
+/// Iterator over the integers in a text, everything between them is skipped. For signed
+/// types a `-` right before the digits makes the number negative, so `x=-3` reads as `-3`
+/// but reads as `3` for unsigned types.
+///
+/// Scanning doesn't allocate. Numbers which don't fit into `T` are returned as errors
+/// with their line and column.
+#[derive(Debug, Clone)]
+pub struct Ints<'a, T> {
+    bytes: &'a [u8],
+    pos: usize,
+    /// 0-based index of the line `bytes` starts at.
+    first_line: usize,
+    int: PhantomData<T>,
+}
+
+/// The integers of `input`, a `&str` or `&[u8]`.
+pub fn ints<T: Int>(input: &(impl AsRef<[u8]> + ?Sized)) -> Ints<'_, T> {
+    Ints::new(input.as_ref(), 0)
+}
+
+/// Exactly `N` integers from every non-blank line of `input`, e.g. the two location IDs of
+/// each line of a list with `ints_per_line::<u32, 2>`.
+pub fn ints_per_line<T: Int, const N: usize>(
+    input: &(impl AsRef<[u8]> + ?Sized),
+) -> impl Iterator<Item = SolutionResult<[T; N]>> + '_ {
+    input
+        .as_ref()
+        .split(|&b| b == b'\n')
+        .enumerate()
+        .filter(|(_, line)| !line.iter().all(u8::is_ascii_whitespace))
+        .map(|(index, line)| Ints::new(line, index).exactly())
+}
+
+impl<'a, T: Int> Ints<'a, T> {
+    fn new(bytes: &'a [u8], first_line: usize) -> Self {
+        Ints {
+            bytes,
+            pos: 0,
+            first_line,
+            int: PhantomData,
+        }
+    }
+
+    /// The next `N` integers, which have to be the last ones.
+    pub fn exactly<const N: usize>(mut self) -> SolutionResult<[T; N]> {
+        let mut values = [T::default(); N];
+        for (found, value) in values.iter_mut().enumerate() {
+            match self.next() {
+                Some(next) => *value = next?,
+                None => {
+                    let message = format!("Expected {} integer(s), found {}", N, found);
+                    return Err(self.error_at(self.bytes.len(), message));
+                }
+            }
+        }
+
+        let end = self.pos;
+        match self.next() {
+            None => Ok(values),
+            Some(_) => {
+                let start = self.start_of_next(end);
+                let message = format!("Expected {} integer(s), found more", N);
+                Err(self.error_at(start, message))
+            }
+        }
+    }
+
+    /// Offset of the first number at or after `from`, its sign included.
+    fn start_of_next(&self, from: usize) -> usize {
+        let mut start = from;
+        while !self.bytes[start].is_ascii_digit() {
+            start += 1;
+        }
+        match T::SIGNED && start > 0 && self.bytes[start - 1] == b'-' {
+            true => start - 1,
+            false => start,
+        }
+    }
+
+    /// Error at byte `offset`, with its line and column.
+    fn error_at(&self, offset: usize, message: String) -> SolutionError {
+        let before = &self.bytes[..offset];
+        let line_start = before
+            .iter()
+            .rposition(|&b| b == b'\n')
+            .map_or(0, |newline| newline + 1);
+        let line = self.first_line + before.iter().filter(|&&b| b == b'\n').count();
+        // Continuation bytes of UTF-8 don't start a new column
+        let column = before[line_start..]
+            .iter()
+            .filter(|&&b| b & 0xC0 != 0x80)
+            .count();
+        SolutionError::at(line + 1, column + 1, message)
+    }
+}
+
+impl<T: Int> Iterator for Ints<'_, T> {
+    type Item = SolutionResult<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.bytes;
+        let mut i = self.pos;
+        while i < bytes.len() && !bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        if i == bytes.len() {
+            self.pos = i;
+            return None;
+        }
+
+        let negative = T::SIGNED && i > 0 && bytes[i - 1] == b'-';
+        let start = if negative { i - 1 } else { i };
+        let mut value = Some(T::default());
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            value = value.and_then(|value| value.push_digit(bytes[i] - b'0', negative));
+            i += 1;
+        }
+        self.pos = i;
+
+        Some(value.ok_or_else(|| {
+            let number = String::from_utf8_lossy(&bytes[start..i]);
+            let message = format!(
+                "`{}` is out of range for {}",
+                number,
+                std::any::type_name::<T>()
+            );
+            self.error_at(start, message)
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ints() {
+        let input = "Button A: X+94, Y-34\nPrize: X=8400, Y=5400\n";
+        let signed: Vec<i64> = ints(input).collect::<SolutionResult<_>>().unwrap();
+        assert_eq!(signed, vec![94, -34, 8400, 5400]);
+        let unsigned: Vec<u16> = ints(input.as_bytes())
+            .collect::<SolutionResult<_>>()
+            .unwrap();
+        assert_eq!(unsigned, vec![94, 34, 8400, 5400]);
+        assert_eq!(ints::<u8>("no numbers - here").count(), 0);
+
+        let extremes: Vec<i8> = ints("-128 127 -0").collect::<SolutionResult<_>>().unwrap();
+        assert_eq!(extremes, vec![-128, 127, 0]);
+        let max = u64::MAX.to_string();
+        assert_eq!(ints::<u64>(&max).next(), Some(Ok(u64::MAX)));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let mut values = ints::<u8>("255 256\n  é 1000 7");
+        assert_eq!(values.next(), Some(Ok(255)));
+        let err = values.next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 5: `256` is out of range for u8"
+        );
+        let err = values.next().unwrap().unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), Some(5)));
+        assert_eq!(values.next(), Some(Ok(7)));
+
+        let err = ints::<i8>("x-129").next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 2: `-129` is out of range for i8"
+        );
+    }
+
+    #[test]
+    fn test_per_line() {
+        let input = "3   4\n4   3\n\n2   5\n";
+        let pairs: Vec<[u32; 2]> = ints_per_line(input).collect::<SolutionResult<_>>().unwrap();
+        assert_eq!(pairs, vec![[3, 4], [4, 3], [2, 5]]);
+
+        let mut lines = ints_per_line::<i32, 2>("3 4\n5\n1 2 -3\n");
+        assert_eq!(lines.next(), Some(Ok([3, 4])));
+        let err = lines.next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 2: Expected 2 integer(s), found 1"
+        );
+        let err = lines.next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 5: Expected 2 integer(s), found more"
+        );
+
+        assert_eq!(ints::<u8>("1, 2, 3").exactly(), Ok([1, 2, 3]));
+    }
+}
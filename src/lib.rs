@@ -1,56 +1,241 @@
+pub mod answer;
+pub mod bench;
+pub mod check;
+pub mod cli;
+pub mod client;
 pub mod days;
-pub use days::day01::Day01;
-pub use days::day02::Day02;
+pub mod error;
+pub mod examples;
+pub mod fetch;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod ints;
+pub mod parse;
+pub mod progress;
+pub mod puzzle;
+pub mod repl;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod sections;
+pub mod submit;
+pub mod watch;
+pub use answer::Answer;
+pub use error::{ErrorKind, SolutionError, SolutionResult};
+pub use grid::Grid;
+pub use input::InputProvider;
+pub use puzzle::{CalendarError, PuzzleId};
 
+use std::sync::{mpsc, Arc, OnceLock};
+use std::thread;
+use std::time::Duration;
+
+/// Calendars of one or more events, ordered by year and day.
 pub struct AdventOfCode {
     pub days: Vec<Day>,
+    provider: Arc<dyn InputProvider>,
 }
 
 pub struct Day {
-    pub day: u32,
-    pub challenge_string: Option<String>,
-    pub solution: Option<Box<dyn Solution>>,
+    pub id: PuzzleId,
+    pub solution: Option<Arc<dyn Solution>>,
+    provider: Arc<dyn InputProvider>,
+    // Loaded on first use, so days which are not run never touch their input
+    input: OnceLock<Result<Arc<str>, String>>,
 }
 
-pub trait Solution {
-    fn challenge1(&self, input: &str) -> String;
-    fn challenge2(&self, input: &str) -> String;
+/// Solution of a single puzzle. Solutions are shared between the threads of the parallel
+/// runner, so they have to be `Send + Sync`.
+pub trait Solution: Send + Sync {
+    fn challenge1(&self, input: &str) -> SolutionResult<Answer>;
+    fn challenge2(&self, input: &str) -> SolutionResult<Answer>;
+
+    /// Parses the input without solving anything, which lets the benchmark tell parse and
+    /// solve time apart. Solutions without a separate parse step keep the default `None`.
+    fn parse(&self, _input: &str) -> Option<SolutionResult<()>> {
+        None
+    }
 }
 
 impl Day {
-    pub fn solve(&self) -> (String, String) {
+    fn new(id: PuzzleId, provider: Arc<dyn InputProvider>) -> Self {
+        Day {
+            id,
+            solution: None,
+            provider,
+            input: OnceLock::new(),
+        }
+    }
+
+    /// The puzzle input, read from the input provider on first use.
+    pub fn input(&self) -> SolutionResult<&str> {
+        self.shared_input().map(|input| &**input)
+    }
+
+    fn shared_input(&self) -> SolutionResult<&Arc<str>> {
+        self.input
+            .get_or_init(|| {
+                self.provider
+                    .load(self.id)
+                    .map(Arc::from)
+                    .map_err(|err| match err.kind() {
+                        std::io::ErrorKind::NotFound => {
+                            format!("Input not found: {}", self.provider.describe(self.id))
+                        }
+                        _ => format!(
+                            "Failed to read {}: {}",
+                            self.provider.describe(self.id),
+                            err
+                        ),
+                    })
+            })
+            .as_ref()
+            .map_err(|err| SolutionError::new(err.clone()))
+    }
+
+    /// Replaces the input of this day, e.g. with a file given on the command line.
+    pub fn set_input(&mut self, input: String) {
+        self.input = OnceLock::from(Ok(Arc::from(input)));
+    }
+
+    pub fn solve(&self) -> (SolutionResult<Answer>, SolutionResult<Answer>) {
+        (self.solve_part(1), self.solve_part(2))
+    }
+
+    /// Solves a single part, errors are tagged with this day and the part.
+    pub fn solve_part(&self, part: u8) -> SolutionResult<Answer> {
         match self.solution {
-            None => ("Not solved yet".to_string(), "Not solved yet".to_string()),
-            Some(ref solution) => (
-                solution.challenge1(self.challenge_string.as_ref().unwrap()),
-                solution.challenge2(self.challenge_string.as_ref().unwrap()),
-            ),
+            None => Ok(Answer::Unsolved),
+            Some(ref solution) => {
+                let input = self.input().map_err(|err| err.for_puzzle(self.id, part))?;
+                challenge(&**solution, input, part).map_err(|err| err.for_puzzle(self.id, part))
+            }
         }
     }
+
+    /// Solves a single part on a worker thread and gives up on it after `timeout`.
+    ///
+    /// A part which runs out of time is reported as `ErrorKind::TimedOut`. Threads can't be
+    /// stopped from the outside, so its worker keeps running in the background until it
+    /// finishes or the process exits.
+    pub fn solve_part_within(&self, part: u8, timeout: Duration) -> SolutionResult<Answer> {
+        let Some(solution) = self.solution.clone() else {
+            return Ok(Answer::Unsolved);
+        };
+        let input = self
+            .shared_input()
+            .map_err(|err| err.for_puzzle(self.id, part))?
+            .clone();
+
+        let (sender, receiver) = mpsc::channel();
+        thread::Builder::new()
+            .name(format!("{} part {}", self.id, part))
+            .spawn(move || {
+                // The receiver is gone once the part timed out, nobody waits for the answer
+                let _ = sender.send(challenge(&*solution, &input, part));
+            })
+            .map_err(|err| {
+                SolutionError::new(format!("Failed to start worker: {}", err))
+                    .for_puzzle(self.id, part)
+            })?;
+
+        match receiver.recv_timeout(timeout) {
+            Ok(answer) => answer,
+            Err(mpsc::RecvTimeoutError::Timeout) => Err(SolutionError::timed_out(timeout)),
+            Err(mpsc::RecvTimeoutError::Disconnected) => Err(SolutionError::panicked()),
+        }
+        .map_err(|err| err.for_puzzle(self.id, part))
+    }
+}
+
+fn challenge(solution: &dyn Solution, input: &str, part: u8) -> SolutionResult<Answer> {
+    match part {
+        1 => solution.challenge1(input),
+        _ => solution.challenge2(input),
+    }
 }
 
 impl AdventOfCode {
+    /// Calendar of the default event, plus every event a registered solution belongs to.
+    /// Inputs are read from the directory found by `DirectoryInput::locate`.
     pub fn new() -> Self {
-        let days: Vec<Day> = (1..=24)
-            .map(|day| {
-                let challenge_string =
-                    std::fs::read_to_string(format!("input/{:0>2}.txt", day)).ok();
-                Day {
-                    day,
-                    challenge_string,
-                    solution: None,
-                }
-            })
-            .collect();
-        AdventOfCode { days }
+        AdventOfCode::with_input(Arc::new(input::DirectoryInput::locate(None)))
+    }
+
+    pub fn with_input(provider: Arc<dyn InputProvider>) -> Self {
+        let mut aoc = AdventOfCode::empty(provider);
+        aoc.add_year(puzzle::YEAR);
+        for (id, solution) in days::registry() {
+            aoc.add_year(id.year);
+            aoc.add_solution(id, solution)
+                .expect("Registered solutions are checked at compile time");
+        }
+        aoc
+    }
+
+    pub fn empty(provider: Arc<dyn InputProvider>) -> Self {
+        AdventOfCode {
+            days: Vec::new(),
+            provider,
+        }
+    }
+
+    /// Switches every day to `provider`, dropping inputs which were already loaded.
+    pub fn set_input_provider(&mut self, provider: Arc<dyn InputProvider>) {
+        for day in self.days.iter_mut() {
+            *day = Day {
+                solution: day.solution.take(),
+                ..Day::new(day.id, provider.clone())
+            };
+        }
+        self.provider = provider;
     }
 
-    pub fn add_solution(&mut self, day: u32, solution: Box<dyn Solution>) {
-        if let Some(d) = self.days.iter_mut().find(|d| d.day == day) {
-            d.solution = Some(solution);
-        } else {
-            eprintln!("Day {} not found!", day);
+    /// Adds the 25 days of `year` to the calendar, does nothing if it is already present.
+    pub fn add_year(&mut self, year: u32) {
+        if self.years().contains(&year) {
+            return;
         }
+
+        self.days.extend(
+            puzzle::DAYS.map(|day| Day::new(PuzzleId { year, day }, self.provider.clone())),
+        );
+        self.days.sort_by_key(|day| day.id);
+    }
+
+    pub fn years(&self) -> Vec<u32> {
+        let mut years: Vec<u32> = self.days.iter().map(|day| day.id.year).collect();
+        years.dedup();
+        years
+    }
+
+    pub fn day(&self, id: PuzzleId) -> Option<&Day> {
+        self.days.iter().find(|d| d.id == id)
+    }
+
+    pub fn day_mut(&mut self, id: PuzzleId) -> Option<&mut Day> {
+        self.days.iter_mut().find(|d| d.id == id)
+    }
+
+    pub fn add_solution(
+        &mut self,
+        id: PuzzleId,
+        solution: Box<dyn Solution>,
+    ) -> Result<(), CalendarError> {
+        match self.day_mut(id) {
+            Some(d) => {
+                d.solution = Some(Arc::from(solution));
+                Ok(())
+            }
+            None => Err(CalendarError::UnknownPuzzle(id)),
+        }
+    }
+}
+
+impl Default for AdventOfCode {
+    fn default() -> Self {
+        AdventOfCode::new()
     }
 }
 
@@ -68,4 +253,68 @@ mod tests {
     fn it_works() {
         start_day("00");
     }
+
+    #[test]
+    fn test_calendar() {
+        let mut aoc = AdventOfCode::empty(Arc::new(input::StringInput(String::new())));
+        aoc.add_year(2023);
+        aoc.add_year(2025);
+        aoc.add_year(2023);
+        assert_eq!(aoc.years(), vec![2023, 2025]);
+        assert_eq!(aoc.days.len(), 50);
+
+        let day25 = PuzzleId::new(2025, 25).unwrap();
+        assert!(aoc.add_solution(day25, days::day01::solution()).is_ok());
+        assert!(aoc.day(day25).unwrap().solution.is_some());
+
+        let unknown = PuzzleId::new(2024, 1).unwrap();
+        assert_eq!(
+            aoc.add_solution(unknown, days::day01::solution()),
+            Err(CalendarError::UnknownPuzzle(unknown))
+        );
+    }
+
+    #[test]
+    fn test_lazy_input() {
+        let mut aoc = AdventOfCode::with_input(Arc::new(input::StringInput("x".to_string())));
+        let id = PuzzleId::day(1).unwrap();
+        assert_eq!(
+            aoc.day(id).unwrap().solve_part(1).unwrap_err().line,
+            Some(1)
+        );
+
+        aoc.day_mut(id).unwrap().set_input("3   4\n".to_string());
+        assert_eq!(aoc.day(id).unwrap().solve_part(1).unwrap(), 1);
+    }
+
+    struct Slow;
+
+    impl Solution for Slow {
+        fn challenge1(&self, input: &str) -> SolutionResult<Answer> {
+            Ok(input.len().into())
+        }
+
+        fn challenge2(&self, _input: &str) -> SolutionResult<Answer> {
+            thread::sleep(Duration::from_secs(2));
+            Ok(0.into())
+        }
+    }
+
+    #[test]
+    fn test_timeout() {
+        let mut aoc = AdventOfCode::empty(Arc::new(input::StringInput("abc".to_string())));
+        aoc.add_year(2023);
+        let id = PuzzleId::new(2023, 1).unwrap();
+        aoc.add_solution(id, Box::new(Slow)).unwrap();
+        let day = aoc.day(id).unwrap();
+
+        let timeout = Duration::from_millis(50);
+        assert_eq!(day.solve_part_within(1, timeout).unwrap(), 3);
+        let err = day.solve_part_within(2, timeout).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::TimedOut);
+        assert_eq!(
+            err.to_string(),
+            "2023 day 01 part 2: Timed out after 50.00ms"
+        );
+    }
 }
@@ -1,17 +1,707 @@
-use adv_code_2024::{AdventOfCode, Day01, Day02};
+use adv_code_2024::bench::{bench_part, Baseline, BASELINE_FILE};
+use adv_code_2024::check::{CheckStatus, ExpectedAnswers, ANSWERS_FILE};
+use adv_code_2024::cli::{CliError, Command, Options, USAGE};
+use adv_code_2024::client::{self, HttpClient, UreqClient};
+use adv_code_2024::examples::{self, FIXTURES_DIR};
+use adv_code_2024::fetch::{Fetched, InputFetcher};
+use adv_code_2024::input::{ArchiveInput, DirectoryInput, InputProvider, StdinInput};
+use adv_code_2024::progress::{self, Progress, PROGRESS_FILE};
+use adv_code_2024::repl::{self, Repl, HISTORY_FILE};
+use adv_code_2024::report::{self, Format};
+use adv_code_2024::runner::{self, Report};
+use adv_code_2024::scaffold;
+use adv_code_2024::submit::{self, Ledger, Reply, Submitter, Verdict, LEDGER_FILE};
+use adv_code_2024::watch::{self, Watcher, POLL_INTERVAL};
+use adv_code_2024::{AdventOfCode, Answer, Day, PuzzleId, SolutionResult};
+use std::path::{Path, PathBuf};
+use std::process::{self, ExitCode};
+use std::sync::Arc;
+use std::time::Duration;
 
+fn main() -> ExitCode {
+    let options = match Options::parse(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("{}\n\n{}", err, USAGE);
+            return ExitCode::from(err.exit_code());
+        }
+    };
 
-fn main() {
-    let mut aoc = AdventOfCode::new();
-    aoc.add_solution(01, Box::new(Day01 {}));
-    aoc.add_solution(02, Box::new(Day02 {}));
+    if options.help {
+        print!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    if options.watch {
+        return match watch(&options) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(err) => {
+                eprintln!("{}", err);
+                ExitCode::from(err.exit_code())
+            }
+        };
+    }
+
+    let provider: Arc<dyn InputProvider> = match &options.input_archive {
+        Some(archive) => Arc::new(ArchiveInput::new(archive)),
+        None => Arc::new(DirectoryInput::locate(options.input_dir.as_deref())),
+    };
+    let mut aoc = AdventOfCode::with_input(provider);
+
+    match run(&mut aoc, &options) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::from(err.exit_code())
+        }
+    }
+}
+
+fn run(aoc: &mut AdventOfCode, options: &Options) -> Result<(), CliError> {
+    if options.command == Command::NewDay {
+        return new_day(aoc, options);
+    }
+    if options.command == Command::Status {
+        return status(options);
+    }
+    if options.command == Command::Repl {
+        return run_repl(aoc, options);
+    }
+
+    if !aoc.years().contains(&options.year) {
+        return Err(CliError::UnknownYear(options.year));
+    }
+
+    let days = match options.puzzles()? {
+        Some(days) => days,
+        None => aoc
+            .days
+            .iter()
+            .filter(|day| day.id.year == options.year && day.solution.is_some())
+            .map(|day| day.id)
+            .collect(),
+    };
+
+    match options.command {
+        Command::Fetch => return fetch(&days, options),
+        Command::Examples => return extract_examples(&days, options),
+        _ => {}
+    }
+    // A given answer doesn't need a solution or an input
+    if options.command == Command::Submit && options.answer.is_some() {
+        return submit(aoc, &days, options);
+    }
+
+    // Validate the whole selection before solving anything
+    for &id in &days {
+        let entry = aoc.day_mut(id).ok_or(CliError::UnknownDay(id.day))?;
+        if entry.solution.is_none() {
+            return Err(CliError::NotSolved(id));
+        }
+        if let Some(path) = &options.input {
+            entry.set_input(read_input(id, path)?);
+        }
+        if let Err(err) = entry.input() {
+            return Err(CliError::MissingInput(err.message));
+        }
+    }
+
+    match options.command {
+        Command::Bench => bench(aoc, &days, options),
+        Command::Run if options.check => check(aoc, &days, options),
+        Command::Run => solve(aoc, &days, options),
+        Command::Submit => submit(aoc, &days, options),
+        Command::NewDay | Command::Fetch | Command::Examples | Command::Status | Command::Repl => {
+            unreachable!("Handled before the validation")
+        }
+    }
+}
+
+fn solve(aoc: &AdventOfCode, days: &[PuzzleId], options: &Options) -> Result<(), CliError> {
+    let parts = options.parts();
+    let days = selected(aoc, days);
+    let report = runner::run(&days, &parts, options.jobs(), options.timeout);
+    if options.format != Format::Text {
+        print!(
+            "{}",
+            report::render(options.format, &report::records(&report, &days))
+        );
+        return match report.failed() {
+            0 => Ok(()),
+            failed => Err(CliError::Failed(failed)),
+        };
+    }
+
+    println!("=== Advent of Code {} ===", options.year);
+    for results in report.results.chunks(parts.len()) {
+        let answers: Vec<String> = results
+            .iter()
+            .map(|result| format!("Part {}: {}", result.part, display(&result.answer)))
+            .collect();
+        println!("Day {:0>2} - {}", results[0].id.day, answers.join(", "));
+    }
+    println!("\n{}", timing(&report, options));
+
+    match report.failed() {
+        0 => Ok(()),
+        failed => Err(CliError::Failed(failed)),
+    }
+}
+
+/// Solves the selected parts and compares them against the answer registry.
+fn check(aoc: &AdventOfCode, days: &[PuzzleId], options: &Options) -> Result<(), CliError> {
+    let path = answers_path(options);
+    let mut answers = ExpectedAnswers::load(&path)
+        .map_err(|err| CliError::Usage(format!("Invalid answer registry: {}", err)))?;
+    let parts = options.parts();
 
-    println!("=== Advent of Code 2024 ===");
-    for day in aoc.days {
-        let (solution1, solution2) = day.solve();
+    println!("=== Advent of Code {} - Check ===", options.year);
+    let header: Vec<String> = parts
+        .iter()
+        .map(|part| format!("Part {:<2}", part))
+        .collect();
+    println!("Day | {}", header.join(" | "));
+    println!("----{}", "+---------".repeat(parts.len()));
+
+    let report = runner::run(
+        &selected(aoc, days),
+        &parts,
+        options.jobs(),
+        options.timeout,
+    );
+    let progress_path = progress_path();
+    let mut progress = load_progress(&progress_path)?;
+    let mut confirmed = 0;
+    let mut details = Vec::new();
+    let mut new_answers = Vec::new();
+    let mut failed = 0;
+    for results in report.results.chunks(parts.len()) {
+        let mut row = Vec::new();
+        for result in results {
+            let (id, part) = (result.id, result.part);
+            let status = answers.check(id, part, &result.answer);
+            row.push(format!("{:<7}", status));
+
+            match (&status, &result.answer) {
+                (CheckStatus::Fail(expected), Ok(answer)) => details.push(format!(
+                    "Day {:0>2} part {}: expected {}, got {}",
+                    id.day, part, expected, answer
+                )),
+                (CheckStatus::Error(err) | CheckStatus::TimedOut(err), _) => {
+                    details.push(err.clone())
+                }
+                (CheckStatus::New, Ok(answer)) => new_answers.push((id, part, answer.clone())),
+                (CheckStatus::Pass, Ok(answer)) => {
+                    confirmed += progress.confirm(id, part, answer, submit::unix_now()) as usize
+                }
+                _ => {}
+            }
+            failed += status.is_failure() as usize;
+        }
+        println!(" {:0>2} | {}", results[0].id.day, row.join(" | "));
+    }
+
+    if !details.is_empty() {
+        println!();
+        details.iter().for_each(|detail| println!("{}", detail));
+    }
+
+    println!("\n{}", timing(&report, options));
+
+    if confirmed > 0 {
+        save_progress(&progress, &progress_path)?;
         println!(
-            "Day {:0>2} - Part 1: {}, Part 2: {}",
-            day.day, solution1, solution2
+            "\nMarked {} part(s) as solved in {}",
+            confirmed,
+            progress_path.display()
         );
     }
+
+    if options.record && !new_answers.is_empty() {
+        let recorded = new_answers.len();
+        for (id, part, answer) in new_answers {
+            answers.insert(id, part, answer);
+        }
+        answers.save(&path).map_err(|err| {
+            CliError::Usage(format!("Failed to write {}: {}", path.display(), err))
+        })?;
+        println!(
+            "\nRecorded {} new answer(s) in {}",
+            recorded,
+            path.display()
+        );
+    }
+
+    match failed {
+        0 => Ok(()),
+        failed => Err(CliError::Failed(failed)),
+    }
+}
+
+/// Benchmarks the selected parts and compares them against the stored baseline.
+fn bench(aoc: &AdventOfCode, days: &[PuzzleId], options: &Options) -> Result<(), CliError> {
+    let bench = &options.bench;
+    let path = match &bench.baseline {
+        Some(path) => path.clone(),
+        None => PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(BASELINE_FILE),
+    };
+    let mut baseline = Baseline::load(&path)
+        .map_err(|err| CliError::Usage(format!("Invalid baseline {}: {}", path.display(), err)))?;
+
+    println!(
+        "=== Advent of Code {} - Bench ({} runs, {} warmup) ===",
+        options.year, bench.config.runs, bench.config.warmup
+    );
+    println!(
+        "Day | Part | {:>10} | {:>10} | {:>10} | {:>10} | {:>10} | Baseline",
+        "Parse", "Solve min", "Median", "p95", "Stddev"
+    );
+
+    let mut failed = 0;
+    let mut regressed = 0;
+    for day in days.iter().filter_map(|&id| aoc.day(id)) {
+        for part in options.parts() {
+            let result = match bench_part(day, part, bench.config) {
+                Ok(result) => result,
+                Err(err) => {
+                    failed += 1;
+                    println!(" {:0>2} | {:>4} | Error ({})", day.id.day, part, err);
+                    continue;
+                }
+            };
+
+            let change = match baseline.change(&result) {
+                Some(change) if change * 100.0 > bench.threshold => {
+                    regressed += 1;
+                    format!("{:+.1}% REGRESSION", change * 100.0)
+                }
+                Some(change) => format!("{:+.1}%", change * 100.0),
+                None => "-".to_string(),
+            };
+            println!(
+                " {:0>2} | {:>4} | {:>10} | {:>10} | {:>10} | {:>10} | {:>10} | {}",
+                day.id.day,
+                part,
+                result
+                    .parse
+                    .map_or("n/a".to_string(), |parse| duration(parse.median)),
+                duration(result.solve.min),
+                duration(result.solve.median),
+                duration(result.solve.p95),
+                duration(result.solve.stddev),
+                change
+            );
+
+            if bench.save_baseline {
+                baseline.insert(result.id, result.part, result.total());
+            }
+        }
+    }
+
+    if bench.save_baseline {
+        baseline.save(&path).map_err(|err| {
+            CliError::Usage(format!("Failed to write {}: {}", path.display(), err))
+        })?;
+        println!("\nSaved baseline to {}", path.display());
+    }
+
+    match (failed, regressed) {
+        (0, 0) => Ok(()),
+        (0, regressed) => Err(CliError::Regressed(regressed)),
+        (failed, _) => Err(CliError::Failed(failed)),
+    }
+}
+
+/// Creates the module and input file of a new day.
+fn new_day(aoc: &AdventOfCode, options: &Options) -> Result<(), CliError> {
+    let id = match options.puzzles()?.as_deref() {
+        Some(&[id]) => id,
+        _ => {
+            return Err(CliError::Usage(
+                "`new-day` requires a single day".to_string(),
+            ))
+        }
+    };
+    if aoc.day(id).is_some_and(|day| day.solution.is_some()) {
+        return Err(CliError::Usage(format!("{} already has a solution", id)));
+    }
+    if options.input_archive.is_some() {
+        return Err(CliError::Usage(
+            "`new-day` can't create inputs in an archive".to_string(),
+        ));
+    }
+
+    let days_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/days");
+    let inputs = DirectoryInput::locate(options.input_dir.as_deref());
+    let created = scaffold::new_day(&days_dir, &inputs, id)
+        .map_err(|err| CliError::Usage(format!("Failed to create {}: {}", id, err)))?;
+
+    println!("Created {}", created.module.display());
+    match created.input {
+        Some(input) => println!("Created {}", input.display()),
+        None => println!("Kept the existing input {}", inputs.path(id).display()),
+    }
+    Ok(())
+}
+
+/// Downloads the inputs of the selected days which are missing from the input directory.
+fn fetch(days: &[PuzzleId], options: &Options) -> Result<(), CliError> {
+    let fetcher = InputFetcher::new(
+        Box::new(UreqClient::default()),
+        client::base_url(),
+        client::session(),
+        DirectoryInput::locate(options.input_dir.as_deref()),
+    );
+
+    let mut failed = 0;
+    for &id in days {
+        match fetcher.fetch(id) {
+            Ok(Fetched::Cached(path)) => println!("{}: cached in {}", id, path.display()),
+            Ok(Fetched::Downloaded(path)) => println!("{}: downloaded to {}", id, path.display()),
+            Err(err) => {
+                failed += 1;
+                println!("{}: {}", id, err);
+            }
+        }
+    }
+
+    match failed {
+        0 => Ok(()),
+        failed => Err(CliError::MissingInput(format!(
+            "{} input(s) could not be fetched",
+            failed
+        ))),
+    }
+}
+
+/// Submits the answer of a single part, unless the ledger has seen it or a cooldown is running.
+fn submit(aoc: &AdventOfCode, days: &[PuzzleId], options: &Options) -> Result<(), CliError> {
+    let (&[id], Some(part)) = (days, options.part) else {
+        return Err(CliError::Usage(
+            "`submit` requires a single `--day` and a `--part`".to_string(),
+        ));
+    };
+    let answer = match &options.answer {
+        Some(answer) => Answer::from(answer.as_str()),
+        None => {
+            let report = runner::run(&selected(aoc, days), &[part], 1, options.timeout);
+            match report
+                .results
+                .into_iter()
+                .next()
+                .map(|result| result.answer)
+            {
+                Some(Ok(Answer::Unsolved)) | None => return Err(CliError::NotSolved(id)),
+                Some(Ok(answer)) => answer,
+                Some(Err(err)) => {
+                    println!("{} part {}: Error ({})", id, part, err);
+                    return Err(CliError::Failed(1));
+                }
+            }
+        }
+    };
+
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(LEDGER_FILE);
+    let mut ledger = Ledger::load(&path)
+        .map_err(|err| CliError::Usage(format!("Invalid submission ledger: {}", err)))?;
+    let submitter = Submitter::new(
+        Box::new(UreqClient::default()),
+        client::base_url(),
+        client::session(),
+    );
+
+    let progress_path = progress_path();
+    let mut progress = load_progress(&progress_path)?;
+
+    println!("Submitting {} for {} part {}", answer, id, part);
+    let now = submit::unix_now();
+    let reply = submitter
+        .submit(&mut ledger, id, part, &answer.to_string(), now)
+        .map_err(|err| CliError::Rejected(err.to_string()))?;
+    ledger
+        .save(&path)
+        .map_err(|err| CliError::Usage(format!("Failed to write {}: {}", path.display(), err)))?;
+    if progress.record(id, part, &answer, &reply, now) {
+        save_progress(&progress, &progress_path)?;
+    }
+
+    match reply {
+        Reply::Judged {
+            verdict: Verdict::Correct,
+            ..
+        } => {
+            println!("{}", reply);
+            let path = answers_path(options);
+            let mut answers = ExpectedAnswers::load(&path)
+                .map_err(|err| CliError::Usage(format!("Invalid answer registry: {}", err)))?;
+            answers.insert(id, part, answer);
+            answers.save(&path).map_err(|err| {
+                CliError::Usage(format!("Failed to write {}: {}", path.display(), err))
+            })?;
+            println!("Recorded the answer in {}", path.display());
+            Ok(())
+        }
+        Reply::Judged { .. } => {
+            println!("{}", reply);
+            Err(CliError::Failed(1))
+        }
+        reply => Err(CliError::Rejected(reply.to_string())),
+    }
+}
+
+/// Writes the examples of a puzzle description into the fixture directory.
+fn extract_examples(days: &[PuzzleId], options: &Options) -> Result<(), CliError> {
+    let &[id] = days else {
+        return Err(CliError::Usage(
+            "`examples` requires a single `--day`".to_string(),
+        ));
+    };
+    let html = match &options.html {
+        Some(path) => std::fs::read_to_string(path).map_err(|err| {
+            CliError::MissingInput(format!("Failed to read {}: {}", path.display(), err))
+        })?,
+        None => {
+            let url = format!("{}/{}/day/{}", client::base_url(), id.year, id.day);
+            let cookie = client::session().map(|session| format!("session={}", session));
+            let headers: Vec<(&str, &str)> = cookie.iter().map(|c| ("Cookie", &**c)).collect();
+            match UreqClient::default().get(&url, &headers) {
+                Ok(response) if response.status == 200 => response.body,
+                Ok(response) => {
+                    return Err(CliError::MissingInput(format!(
+                        "{} responded with status {}",
+                        url, response.status
+                    )))
+                }
+                Err(err) => {
+                    return Err(CliError::MissingInput(format!(
+                        "Failed to download {}: {}",
+                        url, err
+                    )))
+                }
+            }
+        }
+    };
+
+    let found = examples::extract(&html);
+    if found.is_empty() {
+        return Err(CliError::MissingInput(format!(
+            "No examples with answers found for {}",
+            id
+        )));
+    }
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join(FIXTURES_DIR);
+    let dir = examples::save(&root, id, &found)
+        .map_err(|err| CliError::Usage(format!("Failed to write the examples: {}", err)))?;
+
+    for example in &found {
+        let answers: Vec<String> = [1, 2]
+            .into_iter()
+            .filter_map(|part| Some(format!("part {}: {}", part, example.answer(part)?)))
+            .collect();
+        println!(
+            "{}: {}.txt ({})",
+            dir.display(),
+            example.name,
+            answers.join(", ")
+        );
+    }
+    Ok(())
+}
+
+/// Re-runs the selection and its fixture tests in child processes whenever a solution, bin,
+/// input or fixture changes, and shows which answers changed since the previous run.
+fn watch(options: &Options) -> Result<(), CliError> {
+    let crate_root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let inputs = match (&options.input_archive, &options.input) {
+        (Some(archive), _) => archive.clone(),
+        (None, Some(input)) => input.clone(),
+        (None, None) => DirectoryInput::locate(options.input_dir.as_deref()).root,
+    };
+    let filters: Vec<String> = match options.puzzles()? {
+        Some(days) => days.into_iter().map(watch::fixture_filter).collect(),
+        None => vec!["examples::tests::".to_string()],
+    };
+    let args: Vec<String> = std::env::args()
+        .skip(1)
+        .filter(|arg| arg != "--watch" && arg != "-w")
+        .collect();
+    // The children are built with the profile of the watcher
+    let profile: &[&str] = if cfg!(debug_assertions) {
+        &[]
+    } else {
+        &["--release"]
+    };
+    let cargo = || {
+        let mut cargo = process::Command::new(env!("CARGO"));
+        cargo.current_dir(crate_root);
+        cargo
+    };
+
+    let mut watcher = Watcher::new(watch::watched_paths(crate_root, &inputs));
+    let mut previous = Vec::new();
+    loop {
+        print!("\x1b[2J\x1b[H");
+        println!("=== Advent of Code {} - Watch ===", options.year);
+
+        let examples = cargo()
+            .args(["test", "-q", "--lib"])
+            .args(profile)
+            .arg("--")
+            .args(&filters)
+            .output()
+            .map_err(|err| CliError::Usage(format!("Failed to run cargo: {}", err)))?;
+        let stdout = String::from_utf8_lossy(&examples.stdout);
+        match stdout.lines().find(|line| line.starts_with("test result:")) {
+            Some(result) if examples.status.success() => println!("Examples: {}", result),
+            _ => {
+                println!("Examples failed:");
+                print!("{}", stdout);
+                eprint!("{}", String::from_utf8_lossy(&examples.stderr));
+            }
+        }
+
+        let run = cargo()
+            .args(["run", "-q", "--bin", env!("CARGO_BIN_NAME")])
+            .args(profile)
+            .arg("--")
+            .args(&args)
+            .args(["--format", "json"])
+            .output()
+            .map_err(|err| CliError::Usage(format!("Failed to run cargo: {}", err)))?;
+        println!();
+        match serde_json::from_slice::<Vec<report::Record>>(&run.stdout) {
+            Ok(records) => {
+                watch::diff(&previous, &records)
+                    .iter()
+                    .for_each(|line| println!("{}", line));
+                previous = records;
+            }
+            Err(_) => print!("{}", String::from_utf8_lossy(&run.stdout)),
+        }
+        eprint!("{}", String::from_utf8_lossy(&run.stderr));
+
+        let mode = if watcher.is_polling() {
+            ", polling"
+        } else {
+            ""
+        };
+        println!("\nWaiting for changes (Ctrl+C to stop{})...", mode);
+        watcher.wait(POLL_INTERVAL);
+    }
+}
+
+/// Prints the star calendar of the selected year, and the parts of the selected days.
+fn status(options: &Options) -> Result<(), CliError> {
+    let path = progress_path();
+    let progress = load_progress(&path)?;
+
+    println!("=== Advent of Code {} - Progress ===", options.year);
+    print!("{}", progress.calendar(options.year));
+
+    if let Some(days) = options.puzzles()? {
+        println!();
+        for id in days {
+            for part in [1, 2] {
+                let detail = match progress.get(id, part) {
+                    Some(entry) => match (entry.solved_at, &entry.answer) {
+                        (Some(at), Some(answer)) => format!(
+                            "{} (solved {} UTC, {} submission(s))",
+                            answer,
+                            progress::format_time(at),
+                            entry.attempts
+                        ),
+                        _ => format!("not solved, {} submission(s)", entry.attempts),
+                    },
+                    None => "not solved".to_string(),
+                };
+                println!("Day {:0>2} - Part {}: {}", id.day, part, detail);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Runs the REPL on stdin, with the history kept across sessions.
+fn run_repl(aoc: &AdventOfCode, options: &Options) -> Result<(), CliError> {
+    if !aoc.years().contains(&options.year) {
+        return Err(CliError::UnknownYear(options.year));
+    }
+    let crate_root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut repl = Repl::new(aoc, crate_root.join(FIXTURES_DIR), options.year);
+    match options.puzzles()?.as_deref() {
+        None => {}
+        Some(&[id]) if aoc.day(id).is_some_and(|day| day.solution.is_some()) => {
+            repl = repl.with_day(id)
+        }
+        Some(&[id]) => return Err(CliError::NotSolved(id)),
+        Some(_) => {
+            return Err(CliError::Usage(
+                "`repl` starts on a single `--day`".to_string(),
+            ))
+        }
+    }
+
+    let history = crate_root.join(HISTORY_FILE);
+    repl.history = repl::load_history(&history);
+    println!("=== Advent of Code - REPL, `help` lists the commands ===");
+    let result = repl.run(std::io::stdin().lock(), &mut std::io::stdout());
+    // Losing the history is no reason to fail the session
+    let _ = repl::save_history(&history, &repl.history);
+    result.map_err(|err| CliError::Usage(format!("Failed to read the commands: {}", err)))
+}
+
+fn progress_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(PROGRESS_FILE)
+}
+
+fn load_progress(path: &Path) -> Result<Progress, CliError> {
+    Progress::load(path).map_err(|err| CliError::Usage(format!("Invalid progress store: {}", err)))
+}
+
+fn save_progress(progress: &Progress, path: &Path) -> Result<(), CliError> {
+    progress
+        .save(path)
+        .map_err(|err| CliError::Usage(format!("Failed to write {}: {}", path.display(), err)))
+}
+
+fn answers_path(options: &Options) -> PathBuf {
+    match &options.answers {
+        Some(path) => path.clone(),
+        None => PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(ANSWERS_FILE),
+    }
+}
+
+fn selected<'a>(aoc: &'a AdventOfCode, days: &[PuzzleId]) -> Vec<&'a Day> {
+    days.iter().filter_map(|&id| aoc.day(id)).collect()
+}
+
+fn timing(report: &Report, options: &Options) -> String {
+    format!(
+        "{} part(s) in {} wall-clock, {} CPU time, {} job(s)",
+        report.results.len(),
+        duration(report.wall_time),
+        duration(report.cpu_time()),
+        options.jobs()
+    )
+}
+
+fn duration(duration: Duration) -> String {
+    format!("{:.2?}", duration)
+}
+
+fn read_input(id: PuzzleId, path: &Path) -> Result<String, CliError> {
+    let input = match path.to_str() {
+        Some("-") => StdinInput::default().load(id),
+        _ => std::fs::read_to_string(path),
+    };
+    input.map_err(|err| {
+        CliError::MissingInput(format!("Failed to read {}: {}", path.display(), err))
+    })
+}
+
+fn display(solution: &SolutionResult<Answer>) -> String {
+    match solution {
+        Ok(answer) => answer.to_string(),
+        Err(err) => format!("Error ({})", err),
+    }
 }
@@ -0,0 +1,304 @@
+use crate::{SolutionError, SolutionResult};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Part of a line of the input, e.g. the whole line or a field of it.
+///
+/// A `Line` remembers where it came from, so the errors of everything parsed out of it
+/// point at the line and column of the offending text. Lines are split into fields with
+/// `split_once`, `strip_prefix` and `split`, or matched against a pattern like
+/// `"Button {}: X+{}, Y+{}"` with `scan`, and the fields are parsed with `parse`, `list` and
+/// `numbers`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    index: usize,
+    line: &'a str,
+    text: &'a str,
+}
+
+/// Lines of `input`, blank ones included.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| Line::new(index, line))
+}
+
+impl<'a> Line<'a> {
+    /// The line with the 0-based `index`.
+    pub fn new(index: usize, line: &'a str) -> Self {
+        Line {
+            index,
+            line,
+            text: line,
+        }
+    }
+
+    /// 0-based index of the line in the input.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// 1-based column the text starts at.
+    pub fn column(&self) -> usize {
+        let offset = self.text.as_ptr() as usize - self.line.as_ptr() as usize;
+        self.line[..offset].chars().count() + 1
+    }
+
+    pub fn is_blank(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+
+    fn slice(&self, text: &'a str) -> Line<'a> {
+        Line { text, ..*self }
+    }
+
+    /// Error pointing at the start of the text.
+    pub fn error(&self, message: impl Display) -> SolutionError {
+        SolutionError::at(self.index + 1, self.column(), message.to_string())
+    }
+
+    /// Error pointing right after the text.
+    fn error_after(&self, message: impl Display) -> SolutionError {
+        self.slice(&self.text[self.text.len()..]).error(message)
+    }
+
+    pub fn trim(&self) -> Line<'a> {
+        self.slice(self.text.trim())
+    }
+
+    /// Parses the text with surrounding whitespace removed.
+    pub fn parse<T>(&self) -> SolutionResult<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let token = self.trim();
+        if token.text.is_empty() {
+            return Err(token.error("Missing value"));
+        }
+        token
+            .text
+            .parse()
+            .map_err(|err| SolutionError::in_line(self.index, self.line, token.text, err))
+    }
+
+    /// The text after `prefix`, which the text has to start with.
+    pub fn strip_prefix(&self, prefix: &str) -> SolutionResult<Line<'a>> {
+        match self.text.strip_prefix(prefix) {
+            Some(rest) => Ok(self.slice(rest)),
+            None => Err(self.error(format!("Expected `{}`", prefix))),
+        }
+    }
+
+    /// The text before and after the first `separator`, which has to be there.
+    pub fn split_once(&self, separator: &str) -> SolutionResult<(Line<'a>, Line<'a>)> {
+        match self.text.split_once(separator) {
+            Some((before, after)) => Ok((self.slice(before), self.slice(after))),
+            None => Err(self.error_after(format!("Expected `{}`", separator))),
+        }
+    }
+
+    pub fn split(&self, separator: &'a str) -> impl Iterator<Item = Line<'a>> + 'a {
+        let line = *self;
+        self.text.split(separator).map(move |text| line.slice(text))
+    }
+
+    pub fn words(&self) -> impl Iterator<Item = Line<'a>> + 'a {
+        let line = *self;
+        self.text
+            .split_whitespace()
+            .map(move |text| line.slice(text))
+    }
+
+    /// Parses a list of values separated by `separator`, e.g. `"75,47,61"`.
+    pub fn list<T>(&self, separator: &'a str) -> SolutionResult<Vec<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.split(separator).map(|item| item.parse()).collect()
+    }
+
+    /// Parses the whitespace separated values of the text, e.g. `"3   4"`.
+    pub fn numbers<T>(&self) -> SolutionResult<Vec<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.words().map(|word| word.parse()).collect()
+    }
+
+    /// The parts of the text matched by the `{}` of `pattern`, the text in between has to
+    /// match literally. A `{}` extends to the next literal text, or to the end of the line.
+    pub fn captures(&self, pattern: &str) -> SolutionResult<Vec<Line<'a>>> {
+        let mut literals = pattern.split("{}");
+        let mut rest = self.strip_prefix(literals.next().unwrap_or_default())?;
+        let mut captures = Vec::new();
+        let mut literals = literals.peekable();
+        while let Some(literal) = literals.next() {
+            if literal.is_empty() {
+                assert!(
+                    literals.peek().is_none(),
+                    "`{}` has adjacent captures",
+                    pattern
+                );
+                captures.push(rest);
+                rest = rest.slice(&rest.text[rest.text.len()..]);
+            } else {
+                let (capture, after) = rest.split_once(literal)?;
+                captures.push(capture);
+                rest = after;
+            }
+        }
+        if !rest.text.is_empty() {
+            return Err(rest.error(format!("Unexpected `{}`", rest.text)));
+        }
+        Ok(captures)
+    }
+
+    /// Parses the captures of `pattern` into a tuple, see `captures`.
+    pub fn scan<S: Scan>(&self, pattern: &str) -> SolutionResult<S> {
+        S::from_captures(&self.captures(pattern)?)
+    }
+}
+
+/// Tuples of values `Line::scan` can parse captures into.
+pub trait Scan: Sized {
+    fn from_captures(captures: &[Line<'_>]) -> SolutionResult<Self>;
+}
+
+macro_rules! impl_scan {
+    ($count:literal: $($value:ident $index:tt),+) => {
+        impl<$($value),+> Scan for ($($value,)+)
+        where
+            $($value: FromStr, $value::Err: Display,)+
+        {
+            fn from_captures(captures: &[Line<'_>]) -> SolutionResult<Self> {
+                assert_eq!(captures.len(), $count, "Pattern doesn't have {} captures", $count);
+                Ok(($(captures[$index].parse::<$value>()?,)+))
+            }
+        }
+    };
+}
+
+impl_scan!(1: A 0);
+impl_scan!(2: A 0, B 1);
+impl_scan!(3: A 0, B 1, C 2);
+impl_scan!(4: A 0, B 1, C 2, D 3);
+impl_scan!(5: A 0, B 1, C 2, D 3, E 4);
+impl_scan!(6: A 0, B 1, C 2, D 3, E 4, F 5);
+
+/// `Key: value` lines, like the registers of a program:
+///
+/// ```text
+/// Register A: 729
+/// Program: 0,1,5,4,3,0
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Headers<'a> {
+    entries: Vec<(&'a str, Line<'a>)>,
+}
+
+impl<'a> Headers<'a> {
+    /// Splits every non-blank line at the first `separator`.
+    pub fn parse(
+        lines: impl IntoIterator<Item = Line<'a>>,
+        separator: &str,
+    ) -> SolutionResult<Self> {
+        let mut entries = Vec::new();
+        for line in lines.into_iter().filter(|line| !line.is_blank()) {
+            let (key, value) = line.split_once(separator)?;
+            entries.push((key.text.trim(), value));
+        }
+        Ok(Headers { entries })
+    }
+
+    /// Value of the first line with `key`.
+    pub fn get(&self, key: &str) -> SolutionResult<Line<'a>> {
+        self.entries
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, value)| *value)
+            .ok_or_else(|| SolutionError::new(format!("Missing `{}`", key)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(text: &str) -> Line<'_> {
+        Line::new(2, text)
+    }
+
+    #[test]
+    fn test_line() {
+        let (result, operands) = line("3267: 81 40 27").split_once(":").unwrap();
+        assert_eq!(result.parse::<u64>(), Ok(3267));
+        assert_eq!(operands.numbers::<u64>(), Ok(vec![81, 40, 27]));
+        assert_eq!(operands.column(), 6);
+        assert_eq!(line("75, 47,61").list::<u32>(","), Ok(vec![75, 47, 61]));
+
+        let err = line("81 4x 27").numbers::<u64>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 4: invalid digit found in string (`4x`)"
+        );
+        let err = line("81 40").split_once(":").unwrap_err();
+        assert_eq!(err.to_string(), "line 3, column 6: Expected `:`");
+        let err = line("1,,2").list::<u32>(",").unwrap_err();
+        assert_eq!(err.to_string(), "line 3, column 3: Missing value");
+        let err = line("Register A: 7").strip_prefix("Program: ").unwrap_err();
+        assert_eq!(err.to_string(), "line 3, column 1: Expected `Program: `");
+    }
+
+    #[test]
+    fn test_scan() {
+        let pattern = "Button {}: X+{}, Y+{}";
+        let button = line("Button B: X+22, Y+67");
+        assert_eq!(button.scan(pattern), Ok(('B', 22_i64, 67_i64)));
+        assert_eq!(line("Prize: X=8400").scan("Prize: X={}"), Ok((8400,)));
+
+        let err = line("Button B: X+22; Y+67")
+            .scan::<(char, i64, i64)>(pattern)
+            .unwrap_err();
+        assert_eq!(err.to_string(), "line 3, column 21: Expected `, Y+`");
+        let err = line("Button B: X+2é, Y+67")
+            .scan::<(char, i64, i64)>(pattern)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 13: invalid digit found in string (`2é`)"
+        );
+        let err = line("Prize: X= ")
+            .scan::<(u32,)>("Prize: X={}")
+            .unwrap_err();
+        assert_eq!(err.message, "Missing value");
+        let err = line("A=1, B=2").scan::<(u32,)>("A={},").unwrap_err();
+        assert_eq!(err.to_string(), "line 3, column 5: Unexpected ` B=2`");
+    }
+
+    #[test]
+    fn test_headers() {
+        let input = "\
+Register A: 729
+
+Program: 0,1,5,4,3,0
+";
+        let headers = Headers::parse(lines(input), ":").unwrap();
+        assert_eq!(headers.get("Register A").unwrap().parse::<u32>(), Ok(729));
+        let program = headers.get("Program").unwrap();
+        assert_eq!(program.list::<u8>(","), Ok(vec![0, 1, 5, 4, 3, 0]));
+        assert_eq!(program.index(), 2);
+        assert_eq!(
+            headers.get("Register C").unwrap_err().message,
+            "Missing `Register C`"
+        );
+        assert!(Headers::parse(lines(input), "=").is_err());
+    }
+}
@@ -0,0 +1,319 @@
+use crate::submit::{Reply, Verdict};
+use crate::{puzzle, Answer, PuzzleId};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
+
+/// Default location of the progress store, relative to the crate root.
+pub const PROGRESS_FILE: &str = "progress.toml";
+
+/// What is known about one puzzle part.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PartProgress {
+    /// Unix time the part was first confirmed, in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solved_at: Option<u64>,
+    /// The accepted answer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answer: Option<Answer>,
+    /// Answers submitted for the part, right and wrong.
+    #[serde(default)]
+    pub attempts: u32,
+}
+
+impl PartProgress {
+    pub fn is_solved(&self) -> bool {
+        self.solved_at.is_some()
+    }
+}
+
+/// Stars collected per puzzle part, stored as
+///
+/// ```toml
+/// [2024.05.part1]
+/// solved_at = 1733389200
+/// answer = 143
+/// attempts = 2
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Progress {
+    parts: BTreeMap<(PuzzleId, u8), PartProgress>,
+}
+
+type ProgressFile = BTreeMap<String, BTreeMap<String, BTreeMap<String, PartProgress>>>;
+
+impl Progress {
+    /// Reads the store at `path`, a missing file is an empty store.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Progress::default()),
+            Err(err) => return Err(err),
+        };
+        let file: ProgressFile = toml::from_str(&text)
+            .map_err(|err| io::Error::other(format!("{}: {}", path.display(), err)))?;
+
+        let mut progress = Progress::default();
+        for (year, days) in file {
+            for (day, parts) in days {
+                for (part, entry) in parts {
+                    let id = year
+                        .parse()
+                        .ok()
+                        .zip(day.parse().ok())
+                        .and_then(|(year, day)| PuzzleId::new(year, day).ok());
+                    let part = part.strip_prefix("part").and_then(|p| p.parse().ok());
+                    match (id, part) {
+                        (Some(id), Some(part @ 1..=2)) => {
+                            progress.parts.insert((id, part), entry);
+                        }
+                        _ => {
+                            return Err(io::Error::other(format!(
+                                "{}: invalid puzzle {}.{}.{}",
+                                path.display(),
+                                year,
+                                day,
+                                part.map_or("?".to_string(), |p| p.to_string())
+                            )))
+                        }
+                    }
+                }
+            }
+        }
+        Ok(progress)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut file = ProgressFile::new();
+        for ((id, part), entry) in &self.parts {
+            file.entry(id.year.to_string())
+                .or_default()
+                .entry(format!("{:0>2}", id.day))
+                .or_default()
+                .insert(
+                    format!("part{}", part),
+                    PartProgress {
+                        answer: entry.answer.as_ref().map(Answer::to_toml),
+                        ..entry.clone()
+                    },
+                );
+        }
+        std::fs::write(path, toml::to_string(&file).map_err(io::Error::other)?)
+    }
+
+    pub fn get(&self, id: PuzzleId, part: u8) -> Option<&PartProgress> {
+        self.parts.get(&(id, part))
+    }
+
+    /// Records `answer` as accepted at unix time `now`. Returns whether anything changed, a
+    /// part keeps the time it was first solved at.
+    pub fn confirm(&mut self, id: PuzzleId, part: u8, answer: &Answer, now: u64) -> bool {
+        let entry = self.parts.entry((id, part)).or_default();
+        let before = entry.clone();
+        entry.solved_at.get_or_insert(now);
+        entry.answer = Some(answer.clone());
+        *entry != before
+    }
+
+    /// Counts a submission of `part`.
+    pub fn attempt(&mut self, id: PuzzleId, part: u8) {
+        self.parts.entry((id, part)).or_default().attempts += 1;
+    }
+
+    /// Records the reply to a submission of `answer`: every judged answer counts as an
+    /// attempt, a right one also solves the part. Returns whether anything changed.
+    pub fn record(
+        &mut self,
+        id: PuzzleId,
+        part: u8,
+        answer: &Answer,
+        reply: &Reply,
+        now: u64,
+    ) -> bool {
+        match reply {
+            Reply::Judged { verdict, .. } => {
+                self.attempt(id, part);
+                if *verdict == Verdict::Correct {
+                    self.confirm(id, part, answer, now);
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Number of solved parts of `id`.
+    pub fn stars(&self, id: PuzzleId) -> usize {
+        [1, 2]
+            .into_iter()
+            .filter(|&part| self.get(id, part).is_some_and(PartProgress::is_solved))
+            .count()
+    }
+
+    /// 25-day calendar of `year` with a `*` per solved part, followed by the totals.
+    pub fn calendar(&self, year: u32) -> String {
+        let mut calendar = String::new();
+        for week in puzzle::DAYS.collect::<Vec<_>>().chunks(5) {
+            let row: Vec<String> = week
+                .iter()
+                .map(|&day| {
+                    let stars = self.stars(PuzzleId { year, day });
+                    format!("{:>2} {:<2}", day, "*".repeat(stars))
+                })
+                .collect();
+            calendar += row.join("   ").trim_end();
+            calendar += "\n";
+        }
+
+        let entries = || self.parts.iter().filter(|((id, _), _)| id.year == year);
+        let stars = entries().filter(|(_, entry)| entry.is_solved()).count();
+        let complete = puzzle::DAYS
+            .filter(|&day| self.stars(PuzzleId { year, day }) == 2)
+            .count();
+        let attempts: u32 = entries().map(|(_, entry)| entry.attempts).sum();
+        calendar += &format!(
+            "\n{} of {} stars, {} of {} days complete, {} submission(s)\n",
+            stars,
+            2 * puzzle::DAYS.count(),
+            complete,
+            puzzle::DAYS.count(),
+            attempts
+        );
+        calendar
+    }
+}
+
+/// `YYYY-MM-DD HH:MM` in UTC for unix time `secs`.
+pub fn format_time(secs: u64) -> String {
+    // Civil date from days since the epoch, after Howard Hinnant's `civil_from_days`
+    let days = (secs / 86400) as i64 + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + (month <= 2) as i64;
+
+    let time = secs % 86400;
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}",
+        year,
+        month,
+        day,
+        time / 3600,
+        time % 3600 / 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::stub_server;
+    use crate::client::UreqClient;
+    use crate::submit::{Ledger, Submitter};
+
+    #[test]
+    fn test_progress() {
+        let day1 = PuzzleId::day(1).unwrap();
+        let day2 = PuzzleId::day(2).unwrap();
+        let mut progress = Progress::default();
+
+        progress.attempt(day1, 1);
+        progress.attempt(day1, 1);
+        assert!(progress.confirm(day1, 1, &Answer::from(11_u64), 100));
+        assert!(!progress.confirm(day1, 1, &Answer::from(11_u64), 200));
+        assert!(progress.confirm(day1, 2, &Answer::from(31_u64), 300));
+        assert!(progress.confirm(day2, 1, &Answer::from(2_u64), 400));
+
+        let entry = progress.get(day1, 1).unwrap();
+        assert_eq!((entry.solved_at, entry.attempts), (Some(100), 2));
+        assert_eq!((progress.stars(day1), progress.stars(day2)), (2, 1));
+
+        let calendar = progress.calendar(2024);
+        assert!(calendar.starts_with(" 1 **    2 *     3 "));
+        assert!(calendar.ends_with("3 of 50 stars, 1 of 25 days complete, 2 submission(s)\n"));
+        assert!(progress.calendar(2023).starts_with(" 1       2 "));
+
+        let path = std::env::temp_dir().join(format!("aoc-progress-{}.toml", std::process::id()));
+        progress.save(&path).unwrap();
+        assert!(std::fs::read_to_string(&path)
+            .unwrap()
+            .contains("[2024.01.part1]\nsolved_at = 100\nanswer = 11\nattempts = 2\n"));
+        assert_eq!(Progress::load(&path).unwrap(), progress);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_save_wide_answers() {
+        let day1 = PuzzleId::day(1).unwrap();
+        let mut progress = Progress::default();
+        progress.confirm(day1, 1, &Answer::U64(u64::MAX), 100);
+        progress.confirm(day1, 2, &Answer::U128(u128::MAX), 100);
+
+        let path = std::env::temp_dir().join(format!("aoc-wide-{}.toml", std::process::id()));
+        progress.save(&path).unwrap();
+        let text = std::fs::read_to_string(&path).unwrap();
+        assert!(text.contains("answer = \"18446744073709551615\"\n"));
+        assert!(text.contains("answer = \"340282366920938463463374607431768211455\"\n"));
+        let loaded = Progress::load(&path).unwrap();
+        std::fs::remove_file(path).unwrap();
+        assert_eq!(loaded, progress);
+        assert_eq!(
+            loaded.get(day1, 1).unwrap().answer,
+            Some(Answer::U64(u64::MAX))
+        );
+    }
+
+    #[test]
+    fn test_record_submissions() {
+        let id = PuzzleId::day(3).unwrap();
+        let mut progress = Progress::default();
+        let mut ledger = Ledger::default();
+        let mut submit = |answer: u64, message: &str, now: u64| {
+            let (url, server) = stub_server(
+                "200 OK",
+                &format!("<main><article><p>{}</p></article></main>", message),
+            );
+            let submitter = Submitter::new(
+                Box::new(UreqClient::default()),
+                url,
+                Some("token".to_string()),
+            );
+            let answer = Answer::from(answer);
+            let reply = submitter
+                .submit(&mut ledger, id, 1, &answer.to_string(), now)
+                .unwrap();
+            server.join().unwrap();
+            progress.record(id, 1, &answer, &reply, now)
+        };
+
+        assert!(submit(
+            10,
+            "That's not the right answer; your answer is too low.",
+            100
+        ));
+        assert!(!submit(
+            20,
+            "You gave an answer too recently; you have 30s left to wait.",
+            200
+        ));
+        assert!(submit(30, "That's the right answer!", 300));
+
+        let entry = progress.get(id, 1).unwrap();
+        assert_eq!(entry.attempts, 2);
+        assert_eq!(entry.solved_at, Some(300));
+        assert_eq!(entry.answer, Some(Answer::from(30_u64)));
+        assert_eq!(progress.stars(id), 1);
+    }
+
+    #[test]
+    fn test_format_time() {
+        assert_eq!(format_time(0), "1970-01-01 00:00");
+        assert_eq!(format_time(1733389200), "2024-12-05 09:00");
+        assert_eq!(format_time(951782400), "2000-02-29 00:00");
+    }
+}
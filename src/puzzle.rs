@@ -0,0 +1,89 @@
+use std::fmt;
+use std::ops::RangeInclusive;
+
+/// Event this crate was written for, used whenever no year is given.
+pub const YEAR: u32 = 2024;
+
+/// Days of an Advent of Code calendar.
+pub const DAYS: RangeInclusive<u32> = 1..=25;
+
+/// First year Advent of Code took place.
+pub const FIRST_YEAR: u32 = 2015;
+
+/// Identifies a single puzzle of an event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    pub year: u32,
+    pub day: u32,
+}
+
+impl PuzzleId {
+    pub fn new(year: u32, day: u32) -> Result<Self, CalendarError> {
+        let id = PuzzleId { year, day };
+        if year < FIRST_YEAR {
+            return Err(CalendarError::InvalidYear(year));
+        }
+        if !id.is_valid() {
+            return Err(CalendarError::InvalidDay(day));
+        }
+        Ok(id)
+    }
+
+    /// Puzzle of the default event.
+    pub fn day(day: u32) -> Result<Self, CalendarError> {
+        PuzzleId::new(YEAR, day)
+    }
+
+    pub const fn is_valid(&self) -> bool {
+        self.year >= FIRST_YEAR && self.day >= *DAYS.start() && self.day <= *DAYS.end()
+    }
+}
+
+impl fmt::Display for PuzzleId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} day {:0>2}", self.year, self.day)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CalendarError {
+    InvalidYear(u32),
+    InvalidDay(u32),
+    UnknownPuzzle(PuzzleId),
+}
+
+impl fmt::Display for CalendarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CalendarError::InvalidYear(year) => write!(f, "{} is not an Advent of Code year", year),
+            CalendarError::InvalidDay(day) => {
+                write!(f, "Day {} is not part of the calendar (1..=25)", day)
+            }
+            CalendarError::UnknownPuzzle(id) => write!(f, "{} is not in the calendar", id),
+        }
+    }
+}
+
+impl std::error::Error for CalendarError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzle_id() {
+        assert_eq!(
+            PuzzleId::day(25),
+            Ok(PuzzleId {
+                year: 2024,
+                day: 25
+            })
+        );
+        assert_eq!(PuzzleId::day(26), Err(CalendarError::InvalidDay(26)));
+        assert_eq!(
+            PuzzleId::new(2014, 1),
+            Err(CalendarError::InvalidYear(2014))
+        );
+        assert_eq!(PuzzleId::new(2023, 7).unwrap().to_string(), "2023 day 07");
+    }
+}
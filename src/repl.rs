@@ -0,0 +1,391 @@
+use crate::examples::{self, Example};
+use crate::{challenge, AdventOfCode, Answer, PuzzleId, SolutionError, SolutionResult};
+use std::io::{self, BufRead, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// History file of the REPL, relative to the crate root.
+pub const HISTORY_FILE: &str = ".aoc-repl-history";
+
+/// Lines kept in the history file.
+const HISTORY_LIMIT: usize = 500;
+
+pub const HELP: &str = "\
+Commands:
+  day <N>            Switch to day N of the current year
+  year <YEAR>        Switch to another event
+  days               List the days with a registered solution
+  real               Use the real input of the day (default)
+  example [N]        Use example N from the fixtures (default: 1)
+  paste              Read an input from the following lines, ended by a line with a single `.`
+  load <FILE>        Read an input from FILE
+  input              Show the current input
+  1, 2, run [PART]   Solve part 1, part 2 or both
+  time [PART] [N]    Solve N times (default: 10) and show the min and median
+  history            Show the history, `!!` repeats the last command, `!N` command N
+  help               Show this help
+  quit               Leave the REPL
+";
+
+/// Where the input of the current day comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Source {
+    Real,
+    Example(usize),
+    /// Pasted or loaded input, with a description of where it came from.
+    Custom(String, String),
+}
+
+/// Interactive session on the solutions of a calendar.
+pub struct Repl<'a> {
+    aoc: &'a AdventOfCode,
+    fixtures: PathBuf,
+    year: u32,
+    day: Option<PuzzleId>,
+    source: Source,
+    pub history: Vec<String>,
+}
+
+impl<'a> Repl<'a> {
+    /// Session on `aoc`, reading examples from the fixture directory `fixtures`.
+    pub fn new(aoc: &'a AdventOfCode, fixtures: impl Into<PathBuf>, year: u32) -> Self {
+        Repl {
+            aoc,
+            fixtures: fixtures.into(),
+            year,
+            day: None,
+            source: Source::Real,
+            history: Vec::new(),
+        }
+    }
+
+    /// Starts the session on `id`.
+    pub fn with_day(mut self, id: PuzzleId) -> Self {
+        self.year = id.year;
+        self.day = Some(id);
+        self
+    }
+
+    /// Reads commands from `input` until `quit` or the end of the input.
+    pub fn run(&mut self, input: impl BufRead, output: &mut impl Write) -> io::Result<()> {
+        let mut lines = input.lines();
+        loop {
+            write!(output, "{}> ", self.prompt())?;
+            output.flush()?;
+            let Some(line) = lines.next() else {
+                writeln!(output)?;
+                return Ok(());
+            };
+            let mut line = line?.trim().to_string();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(reference) = line.strip_prefix('!') {
+                let entry = match reference {
+                    "!" => self.history.last(),
+                    n => n
+                        .parse::<usize>()
+                        .ok()
+                        .and_then(|n| self.history.get(n.wrapping_sub(1))),
+                };
+                match entry {
+                    Some(entry) => {
+                        line = entry.clone();
+                        writeln!(output, "{}", line)?;
+                    }
+                    None => {
+                        writeln!(output, "No history entry `{}`", line)?;
+                        continue;
+                    }
+                }
+            }
+            self.history.push(line.clone());
+
+            if !self.execute(&line, &mut lines, output)? {
+                return Ok(());
+            }
+        }
+    }
+
+    fn prompt(&self) -> String {
+        let Some(id) = self.day else {
+            return format!("aoc {}", self.year);
+        };
+        match &self.source {
+            Source::Real => format!("aoc {}", id),
+            Source::Example(n) => format!("aoc {} (example {})", id, n),
+            Source::Custom(name, _) => format!("aoc {} ({})", id, name),
+        }
+    }
+
+    /// Runs a single command, returns `false` to end the session.
+    fn execute(
+        &mut self,
+        line: &str,
+        lines: &mut impl Iterator<Item = io::Result<String>>,
+        output: &mut impl Write,
+    ) -> io::Result<bool> {
+        let mut words = line.split_whitespace();
+        let command = words.next().unwrap_or_default();
+        let args: Vec<&str> = words.collect();
+
+        match (command, args.as_slice()) {
+            ("quit" | "exit" | "q", _) => return Ok(false),
+            ("help" | "?", _) => write!(output, "{}", HELP)?,
+            ("history", _) => {
+                for (n, entry) in self.history.iter().enumerate() {
+                    writeln!(output, "{:>4}  {}", n + 1, entry)?;
+                }
+            }
+            ("days", _) => {
+                let days: Vec<String> = self
+                    .aoc
+                    .days
+                    .iter()
+                    .filter(|day| day.id.year == self.year && day.solution.is_some())
+                    .map(|day| day.id.day.to_string())
+                    .collect();
+                writeln!(output, "Solved days of {}: {}", self.year, days.join(", "))?;
+            }
+            ("year", [year]) => match year.parse() {
+                Ok(year) if self.aoc.years().contains(&year) => {
+                    self.year = year;
+                    self.day = None;
+                    self.source = Source::Real;
+                }
+                _ => writeln!(output, "Year {} is not in the calendar", year)?,
+            },
+            ("day", [day]) => match day.parse().map(|day| PuzzleId::new(self.year, day)) {
+                Ok(Ok(id)) if self.aoc.day(id).is_some_and(|day| day.solution.is_some()) => {
+                    self.day = Some(id);
+                    self.source = Source::Real;
+                }
+                Ok(Ok(id)) => writeln!(output, "{} has no registered solution", id)?,
+                _ => writeln!(output, "Invalid day `{}`", day)?,
+            },
+            ("real", []) => self.source = Source::Real,
+            ("example", [] | [_]) => {
+                let n = match args.first().map(|n| n.parse::<usize>()) {
+                    None => 1,
+                    Some(Ok(n)) if n >= 1 => n,
+                    Some(_) => {
+                        writeln!(output, "Invalid example `{}`", args[0])?;
+                        return Ok(true);
+                    }
+                };
+                match self.examples().map(|examples| examples.len()) {
+                    Ok(count) if n <= count => self.source = Source::Example(n),
+                    Ok(count) => writeln!(output, "Only {} example(s) in the fixtures", count)?,
+                    Err(err) => writeln!(output, "{}", err)?,
+                }
+            }
+            ("paste", []) => {
+                let mut input = String::new();
+                for line in lines.by_ref() {
+                    let line = line?;
+                    if line == "." {
+                        break;
+                    }
+                    input.push_str(&line);
+                    input.push('\n');
+                }
+                writeln!(output, "Read {} line(s)", input.lines().count())?;
+                self.source = Source::Custom("pasted".to_string(), input);
+            }
+            ("load", [path]) => match std::fs::read_to_string(path) {
+                Ok(input) => self.source = Source::Custom(path.to_string(), input),
+                Err(err) => writeln!(output, "Failed to read {}: {}", path, err)?,
+            },
+            ("input", []) => match self.input() {
+                Ok(input) => {
+                    write!(output, "{}", input)?;
+                    if !input.ends_with('\n') {
+                        writeln!(output)?;
+                    }
+                    writeln!(
+                        output,
+                        "({} line(s), {} byte(s))",
+                        input.lines().count(),
+                        input.len()
+                    )?;
+                }
+                Err(err) => writeln!(output, "{}", err)?,
+            },
+            ("1" | "2", []) => self.solve(&[command.parse().unwrap()], output)?,
+            ("run", []) => self.solve(&[1, 2], output)?,
+            ("run", [part @ ("1" | "2")]) => self.solve(&[part.parse().unwrap()], output)?,
+            ("time", [] | [_] | [_, _]) => {
+                let part = args.first().map_or(Ok(1), |part| part.parse::<u8>());
+                let runs = args.get(1).map_or(Ok(10), |runs| runs.parse::<usize>());
+                match (part, runs) {
+                    (Ok(part @ 1..=2), Ok(runs @ 1..)) => self.time(part, runs, output)?,
+                    _ => writeln!(output, "Usage: time [1|2] [RUNS]")?,
+                }
+            }
+            _ => writeln!(output, "Unknown command `{}`, try `help`", line)?,
+        }
+        Ok(true)
+    }
+
+    fn examples(&self) -> Result<Vec<Example>, String> {
+        let id = self.day.ok_or("Select a day first, e.g. `day 5`")?;
+        examples::load(&self.fixtures, id).map_err(|err| err.to_string())
+    }
+
+    /// The input selected for the current day, and the expected answers of an example.
+    fn input_with_example(&self) -> Result<(String, Option<Example>), String> {
+        let id = self.day.ok_or("Select a day first, e.g. `day 5`")?;
+        match &self.source {
+            Source::Real => {
+                let day = self.aoc.day(id).ok_or("Day not in the calendar")?;
+                Ok((day.input().map_err(|err| err.message)?.to_string(), None))
+            }
+            Source::Example(n) => {
+                let example = self
+                    .examples()?
+                    .into_iter()
+                    .nth(n - 1)
+                    .ok_or_else(|| format!("Example {} is gone from the fixtures", n))?;
+                Ok((example.input.clone(), Some(example)))
+            }
+            Source::Custom(_, input) => Ok((input.clone(), None)),
+        }
+    }
+
+    fn input(&self) -> Result<String, String> {
+        self.input_with_example().map(|(input, _)| input)
+    }
+
+    fn challenge(&self, input: &str, part: u8) -> SolutionResult<Answer> {
+        let id = self.day.expect("Checked by the caller");
+        let solution = self
+            .aoc
+            .day(id)
+            .and_then(|day| day.solution.as_deref())
+            .expect("Days are only selected with a solution");
+        // A panicking solution shouldn't end the session
+        panic::catch_unwind(AssertUnwindSafe(|| challenge(solution, input, part)))
+            .unwrap_or_else(|_| Err(SolutionError::panicked()))
+    }
+
+    fn solve(&self, parts: &[u8], output: &mut impl Write) -> io::Result<()> {
+        let (input, example) = match self.input_with_example() {
+            Ok(input) => input,
+            Err(err) => return writeln!(output, "{}", err),
+        };
+        for &part in parts {
+            let start = Instant::now();
+            let answer = self.challenge(&input, part);
+            let elapsed = start.elapsed();
+
+            let answer = match answer {
+                Ok(answer) => {
+                    let expected = example.as_ref().and_then(|example| example.answer(part));
+                    match expected {
+                        Some(expected) if *expected == answer => {
+                            format!("{} (as expected)", answer)
+                        }
+                        Some(expected) => format!("{} (expected {})", answer, expected),
+                        None => answer.to_string(),
+                    }
+                }
+                Err(err) => format!("Error ({})", err),
+            };
+            writeln!(output, "Part {}: {}   [{:.2?}]", part, answer, elapsed)?;
+        }
+        Ok(())
+    }
+
+    fn time(&self, part: u8, runs: usize, output: &mut impl Write) -> io::Result<()> {
+        let input = match self.input() {
+            Ok(input) => input,
+            Err(err) => return writeln!(output, "{}", err),
+        };
+        let mut samples: Vec<Duration> = Vec::with_capacity(runs);
+        for _ in 0..runs {
+            let start = Instant::now();
+            if let Err(err) = self.challenge(&input, part) {
+                return writeln!(output, "Part {}: Error ({})", part, err);
+            }
+            samples.push(start.elapsed());
+        }
+        samples.sort();
+        writeln!(
+            output,
+            "Part {}: min {:.2?}, median {:.2?} over {} run(s)",
+            part,
+            samples[0],
+            samples[samples.len() / 2],
+            runs
+        )
+    }
+}
+
+/// Reads the history file at `path`, missing or unreadable files are an empty history.
+pub fn load_history(path: &std::path::Path) -> Vec<String> {
+    std::fs::read_to_string(path)
+        .map(|history| history.lines().map(str::to_string).collect())
+        .unwrap_or_default()
+}
+
+/// Writes the last `HISTORY_LIMIT` entries of `history` to `path`.
+pub fn save_history(path: &std::path::Path, history: &[String]) -> io::Result<()> {
+    let start = history.len().saturating_sub(HISTORY_LIMIT);
+    let mut text = history[start..].join("\n");
+    text.push('\n');
+    std::fs::write(path, text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::StringInput;
+    use std::sync::Arc;
+
+    fn session(script: &str) -> (String, Vec<String>) {
+        let aoc = AdventOfCode::with_input(Arc::new(StringInput("1   2\n3   4\n".to_string())));
+        let fixtures = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures");
+        let mut repl = Repl::new(&aoc, fixtures, crate::puzzle::YEAR);
+        let mut output = Vec::new();
+        repl.run(script.as_bytes(), &mut output).unwrap();
+        (String::from_utf8(output).unwrap(), repl.history)
+    }
+
+    #[test]
+    fn test_session() {
+        let (output, history) =
+            session("1\nday 1\nrun\nexample\n2\npaste\n1   1\n.\n!3\nreal\ninput\nquit\nrun\n");
+        assert!(output.contains("Select a day first"));
+        assert!(output.contains("Part 1: 2   ["));
+        assert!(output.contains("aoc 2024 day 01 (example 1)> Part 2: 31 (as expected)"));
+        assert!(output.contains("Read 1 line(s)"));
+        assert!(output.contains("aoc 2024 day 01 (pasted)> run\nPart 1: 0"));
+        assert!(output.contains("1   2\n3   4\n(2 line(s), 12 byte(s))"));
+        assert_eq!(history.len(), 10);
+        assert_eq!(history[6], "run");
+    }
+
+    #[test]
+    fn test_errors() {
+        let (output, _) = session("day 30\nday 20\nday 1\nexample 9\ntime 3\n!42\nfoo\n");
+        assert!(output.contains("Invalid day `30`"));
+        assert!(output.contains("2024 day 20 has no registered solution"));
+        assert!(output.contains("Only 1 example(s) in the fixtures"));
+        assert!(output.contains("Usage: time [1|2] [RUNS]"));
+        assert!(output.contains("No history entry `!42`"));
+        assert!(output.contains("Unknown command `foo`, try `help`"));
+    }
+
+    #[test]
+    fn test_history_file() {
+        let path = std::env::temp_dir().join(format!("aoc-repl-{}", std::process::id()));
+        let history: Vec<String> = (0..HISTORY_LIMIT + 5).map(|n| n.to_string()).collect();
+        save_history(&path, &history).unwrap();
+        let loaded = load_history(&path);
+        assert_eq!(loaded.len(), HISTORY_LIMIT);
+        assert_eq!(loaded[0], "5");
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,228 @@
+use crate::runner::{PartResult, Report};
+use crate::{Answer, Day, ErrorKind};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Output format of the runner.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+    Markdown,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            "markdown" | "md" => Ok(Format::Markdown),
+            other => Err(format!("Unknown format `{}`", other)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Solved,
+    Unsolved,
+    Error,
+    #[serde(rename = "timeout")]
+    TimedOut,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Solved => f.pad("solved"),
+            Status::Unsolved => f.pad("unsolved"),
+            Status::Error => f.pad("error"),
+            Status::TimedOut => f.pad("timeout"),
+        }
+    }
+}
+
+/// Outcome of one puzzle part as written by the machine-readable formats.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    pub year: u32,
+    pub day: u32,
+    pub part: u8,
+    pub status: Status,
+    pub answer: Answer,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub duration_ns: u64,
+    /// FNV-1a hash of the input, tells apart runs on different inputs.
+    pub input_hash: String,
+}
+
+impl Record {
+    pub fn new(day: &Day, result: &PartResult) -> Self {
+        let (status, answer, error) = match &result.answer {
+            Ok(Answer::Unsolved) => (Status::Unsolved, Answer::Unsolved, None),
+            Ok(answer) => (Status::Solved, answer.clone(), None),
+            Err(err) if err.kind == ErrorKind::TimedOut => {
+                (Status::TimedOut, Answer::Unsolved, Some(err.to_string()))
+            }
+            Err(err) => (Status::Error, Answer::Unsolved, Some(err.to_string())),
+        };
+        Record {
+            year: result.id.year,
+            day: result.id.day,
+            part: result.part,
+            status,
+            answer,
+            error,
+            duration_ns: result.duration.as_nanos() as u64,
+            input_hash: day.input().map(input_hash).unwrap_or_default(),
+        }
+    }
+}
+
+/// Records of every part in `report`, `days` has to hold the days the report was run on.
+pub fn records(report: &Report, days: &[&Day]) -> Vec<Record> {
+    report
+        .results
+        .iter()
+        .filter_map(|result| {
+            let day = days.iter().find(|day| day.id == result.id)?;
+            Some(Record::new(day, result))
+        })
+        .collect()
+}
+
+/// 64-bit FNV-1a hash of `input` as hex string.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+/// Renders `records` in one of the machine-readable formats, `Format::Text` is left to the
+/// caller.
+pub fn render(format: Format, records: &[Record]) -> String {
+    match format {
+        Format::Text => String::new(),
+        Format::Json => {
+            serde_json::to_string_pretty(records).expect("Records serialize to JSON") + "\n"
+        }
+        Format::Csv => {
+            let mut csv = "year,day,part,status,answer,error,duration_ns,input_hash\n".to_string();
+            for record in records {
+                let fields = [
+                    record.year.to_string(),
+                    record.day.to_string(),
+                    record.part.to_string(),
+                    record.status.to_string(),
+                    answer(record),
+                    record.error.clone().unwrap_or_default(),
+                    record.duration_ns.to_string(),
+                    record.input_hash.clone(),
+                ];
+                let fields: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+                csv += &(fields.join(",") + "\n");
+            }
+            csv
+        }
+        Format::Markdown => {
+            let mut markdown = "| Year | Day | Part | Status | Answer | Time | Input |\n\
+                                |------|-----|------|--------|--------|------|-------|\n"
+                .to_string();
+            for record in records {
+                let answer = match &record.error {
+                    Some(err) => err.clone(),
+                    None => answer(record),
+                };
+                markdown += &format!(
+                    "| {} | {:0>2} | {} | {} | {} | {:.2?} | `{}` |\n",
+                    record.year,
+                    record.day,
+                    record.part,
+                    record.status,
+                    answer.replace('|', "\\|"),
+                    std::time::Duration::from_nanos(record.duration_ns),
+                    record.input_hash
+                );
+            }
+            markdown
+        }
+    }
+}
+
+fn answer(record: &Record) -> String {
+    match record.answer {
+        Answer::Unsolved => String::new(),
+        ref answer => answer.to_string(),
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_records() -> Vec<Record> {
+        let record = Record {
+            year: 2024,
+            day: 1,
+            part: 1,
+            status: Status::Solved,
+            answer: Answer::U64(11),
+            error: None,
+            duration_ns: 1500,
+            input_hash: input_hash(""),
+        };
+        vec![
+            record.clone(),
+            Record {
+                part: 2,
+                status: Status::Error,
+                answer: Answer::Unsolved,
+                error: Some("line 1: bad \"x\", y".to_string()),
+                ..record
+            },
+        ]
+    }
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn test_render() {
+        let json: serde_json::Value =
+            serde_json::from_str(&render(Format::Json, &sample_records())).unwrap();
+        assert_eq!(json[0]["answer"], 11);
+        assert_eq!(json[0]["status"], "solved");
+        assert_eq!(json[1]["answer"], serde_json::Value::Null);
+
+        let csv = render(Format::Csv, &sample_records());
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[1], "2024,1,1,solved,11,,1500,cbf29ce484222325");
+        assert_eq!(
+            lines[2],
+            "2024,1,2,error,,\"line 1: bad \"\"x\"\", y\",1500,cbf29ce484222325"
+        );
+
+        let markdown = render(Format::Markdown, &sample_records());
+        assert!(markdown.contains("| 2024 | 01 | 1 | solved | 11 | 1.50µs | `cbf29ce484222325` |"));
+    }
+}
@@ -0,0 +1,166 @@
+use crate::{Answer, Day, PuzzleId, SolutionError, SolutionResult};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Outcome of a single puzzle part.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartResult {
+    pub id: PuzzleId,
+    pub part: u8,
+    pub answer: SolutionResult<Answer>,
+    pub duration: Duration,
+}
+
+/// Results of a run in the order the parts were requested.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub results: Vec<PartResult>,
+    pub wall_time: Duration,
+}
+
+impl Report {
+    /// Time spent in solutions summed over every part, which exceeds the wall-clock time
+    /// when parts ran in parallel.
+    pub fn cpu_time(&self) -> Duration {
+        self.results.iter().map(|result| result.duration).sum()
+    }
+
+    pub fn failed(&self) -> usize {
+        self.results.iter().filter(|r| r.answer.is_err()).count()
+    }
+}
+
+/// Number of worker threads used when none is given.
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, |jobs| jobs.get())
+}
+
+/// Solves `parts` of every day on up to `jobs` threads, giving each part at most `timeout`.
+///
+/// Workers pick the next unsolved part as soon as they are done, so one slow part does not
+/// hold up the others. The report lists the parts day by day regardless of which finished
+/// first.
+pub fn run(days: &[&Day], parts: &[u8], jobs: usize, timeout: Option<Duration>) -> Report {
+    let tasks: Vec<(&Day, u8)> = days
+        .iter()
+        .flat_map(|&day| parts.iter().map(move |&part| (day, part)))
+        .collect();
+    let results: Vec<Mutex<Option<PartResult>>> = tasks.iter().map(|_| Mutex::new(None)).collect();
+    let next = AtomicUsize::new(0);
+
+    let start = Instant::now();
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, tasks.len().max(1)) {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(&(day, part)) = tasks.get(index) else {
+                    break;
+                };
+                let start = Instant::now();
+                let answer = match timeout {
+                    Some(timeout) => day.solve_part_within(part, timeout),
+                    // A panicking part shouldn't take the worker and the whole run down
+                    None => panic::catch_unwind(AssertUnwindSafe(|| day.solve_part(part)))
+                        .unwrap_or_else(
+                            |_| Err(SolutionError::panicked().for_puzzle(day.id, part)),
+                        ),
+                };
+                *results[index].lock().unwrap() = Some(PartResult {
+                    id: day.id,
+                    part,
+                    answer,
+                    duration: start.elapsed(),
+                });
+            });
+        }
+    });
+
+    Report {
+        results: results
+            .into_iter()
+            .map(|result| result.into_inner().unwrap().expect("Every task ran"))
+            .collect(),
+        wall_time: start.elapsed(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::StringInput;
+    use crate::{AdventOfCode, ErrorKind, Solution};
+    use std::sync::Arc;
+
+    struct Sleep;
+
+    impl Solution for Sleep {
+        fn challenge1(&self, _input: &str) -> SolutionResult<Answer> {
+            thread::sleep(Duration::from_millis(100));
+            Ok(1.into())
+        }
+
+        fn challenge2(&self, _input: &str) -> SolutionResult<Answer> {
+            thread::sleep(Duration::from_millis(50));
+            Ok(2.into())
+        }
+    }
+
+    struct Panic;
+
+    impl Solution for Panic {
+        fn challenge1(&self, input: &str) -> SolutionResult<Answer> {
+            Ok((1 / input.len()).into())
+        }
+
+        fn challenge2(&self, _input: &str) -> SolutionResult<Answer> {
+            Ok(2.into())
+        }
+    }
+
+    #[test]
+    fn test_parallel_run() {
+        let mut aoc = AdventOfCode::empty(Arc::new(StringInput(String::new())));
+        aoc.add_year(2023);
+        for day in 1..=3 {
+            let id = PuzzleId::new(2023, day).unwrap();
+            aoc.add_solution(id, Box::new(Sleep)).unwrap();
+        }
+        let days: Vec<&Day> = aoc.days.iter().filter(|d| d.solution.is_some()).collect();
+
+        let report = run(&days, &[1, 2], 6, None);
+        let order: Vec<(u32, u8)> = report.results.iter().map(|r| (r.id.day, r.part)).collect();
+        assert_eq!(order, vec![(1, 1), (1, 2), (2, 1), (2, 2), (3, 1), (3, 2)]);
+        assert_eq!(report.results[4].answer, Ok(Answer::U64(1)));
+        assert_eq!(report.failed(), 0);
+        assert!(report.cpu_time() >= Duration::from_millis(450));
+        assert!(report.wall_time < report.cpu_time());
+
+        let report = run(&days[..1], &[1, 2], 1, Some(Duration::from_millis(75)));
+        assert_eq!(
+            report.results[0].answer.as_ref().unwrap_err().kind,
+            ErrorKind::TimedOut
+        );
+        assert_eq!(report.results[1].answer, Ok(Answer::U64(2)));
+    }
+
+    #[test]
+    fn test_panicking_part() {
+        let mut aoc = AdventOfCode::empty(Arc::new(StringInput(String::new())));
+        aoc.add_year(2023);
+        for day in 1..=2 {
+            let id = PuzzleId::new(2023, day).unwrap();
+            aoc.add_solution(id, Box::new(Panic)).unwrap();
+        }
+        let days: Vec<&Day> = aoc.days.iter().filter(|d| d.solution.is_some()).collect();
+
+        let report = run(&days, &[1, 2], 1, None);
+        let err = report.results[0].answer.as_ref().unwrap_err();
+        assert_eq!(err.kind, ErrorKind::Panicked);
+        assert_eq!((err.puzzle, err.part), (Some(days[0].id), Some(1)));
+        assert_eq!(report.results[1].answer, Ok(Answer::U64(2)));
+        assert_eq!(report.failed(), 2);
+    }
+}
@@ -0,0 +1,155 @@
+use crate::input::DirectoryInput;
+use crate::{puzzle, PuzzleId};
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = r#"use crate::{Answer, Solution, SolutionResult};
+
+crate::register_solution!({register});
+
+pub struct {name} {}
+impl Solution for {name} {
+    fn challenge1(&self, _input: &str) -> SolutionResult<Answer> {
+        // TODO: Solve part 1
+        Ok(Answer::Unsolved)
+    }
+
+    fn challenge2(&self, _input: &str) -> SolutionResult<Answer> {
+        // TODO: Solve part 2
+        Ok(Answer::Unsolved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // TODO: Add the example input
+    const INPUT: &str = "\
+";
+
+    #[test]
+    fn test_challenge1() {
+        let day = {name} {};
+        // TODO: Set the expected answer for the example
+        assert_eq!(day.challenge1(INPUT).unwrap(), Answer::Unsolved);
+    }
+
+    #[test]
+    fn test_challenge2() {
+        let day = {name} {};
+        assert_eq!(day.challenge2(INPUT).unwrap(), Answer::Unsolved);
+    }
+}
+"#;
+
+/// Name of the `src/days` module of `id`: `day05`, or `day2023_05` for other events.
+pub fn module_name(id: PuzzleId) -> String {
+    if id.year == puzzle::YEAR {
+        format!("day{:0>2}", id.day)
+    } else {
+        format!("day{}_{:0>2}", id.year, id.day)
+    }
+}
+
+/// Source of a `Solution` skeleton for `id` with a test module for the example input.
+pub fn module_source(id: PuzzleId) -> String {
+    let name = format!("Day{:0>2}", id.day);
+    let register = if id.year == puzzle::YEAR {
+        format!("{}, {} {{}}", id.day, name)
+    } else {
+        format!("{}, {}, {} {{}}", id.year, id.day, name)
+    };
+    TEMPLATE
+        .replace("{register}", &register)
+        .replace("{name}", &name)
+}
+
+/// Files written by `new_day`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewDay {
+    pub module: PathBuf,
+    /// The empty input file, `None` if an input was already there.
+    pub input: Option<PathBuf>,
+}
+
+/// Creates the module of `id` in `days_dir` and an empty input file in `inputs`.
+///
+/// Fails with `io::ErrorKind::AlreadyExists` if the module exists, an existing input is kept.
+/// The module registers itself, the build script picks it up on the next build.
+pub fn new_day(days_dir: &Path, inputs: &DirectoryInput, id: PuzzleId) -> io::Result<NewDay> {
+    let module = days_dir.join(format!("{}.rs", module_name(id)));
+    create_new(&module, &module_source(id)).map_err(|err| match err.kind() {
+        io::ErrorKind::AlreadyExists => io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", module.display()),
+        ),
+        _ => err,
+    })?;
+
+    let input = inputs.path(id);
+    if let Some(dir) = input.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let input = match create_new(&input, "") {
+        Ok(()) => Some(input),
+        Err(err) if err.kind() == io::ErrorKind::AlreadyExists => None,
+        Err(err) => return Err(err),
+    };
+
+    Ok(NewDay { module, input })
+}
+
+fn create_new(path: &Path, content: &str) -> io::Result<()> {
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)?
+        .write_all(content.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_module_source() {
+        let source = module_source(PuzzleId::day(5).unwrap());
+        assert!(source.contains("crate::register_solution!(5, Day05 {});"));
+        assert!(source.contains("impl Solution for Day05 {"));
+
+        let id = PuzzleId::new(2023, 25).unwrap();
+        assert_eq!(module_name(id), "day2023_25");
+        assert!(module_source(id).contains("crate::register_solution!(2023, 25, Day25 {});"));
+    }
+
+    #[test]
+    fn test_new_day() {
+        let dir = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let inputs = DirectoryInput::new(dir.join("input"));
+        let id = PuzzleId::day(9).unwrap();
+
+        let created = new_day(&dir, &inputs, id).unwrap();
+        assert_eq!(created.module, dir.join("day09.rs"));
+        assert_eq!(created.input, Some(dir.join("input/09.txt")));
+        assert_eq!(
+            std::fs::read_to_string(dir.join("input/09.txt")).unwrap(),
+            ""
+        );
+
+        std::fs::write(&created.module, "// solved").unwrap();
+        let err = new_day(&dir, &inputs, id).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        assert_eq!(
+            std::fs::read_to_string(&created.module).unwrap(),
+            "// solved"
+        );
+
+        std::fs::remove_file(&created.module).unwrap();
+        assert_eq!(new_day(&dir, &inputs, id).unwrap().input, None);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
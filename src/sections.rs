@@ -0,0 +1,223 @@
+use crate::parse::{self, Headers, Line};
+use crate::{SolutionError, SolutionResult};
+use std::fmt::Display;
+
+/// Sections of an input separated by blank lines, in input order, e.g. the page ordering
+/// rules and the updates of a print queue.
+///
+/// A day with a fixed layout declares a parser per section and gets the values back as a
+/// tuple, with the number of sections checked first:
+/// `Sections::new(input).parse((parse_rules, parse_updates))?`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Sections<'a> {
+    sections: Vec<Section<'a>>,
+}
+
+/// Lines of one section, never empty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section<'a> {
+    text: &'a str,
+    lines: Vec<Line<'a>>,
+}
+
+impl<'a> Sections<'a> {
+    /// Splits `input` at blank lines. Several blank lines in a row separate two sections as
+    /// well, blank lines at the start and the end are ignored.
+    pub fn new(input: &'a str) -> Self {
+        let mut groups = vec![Vec::new()];
+        for line in parse::lines(input) {
+            match line.is_blank() {
+                true => groups.push(Vec::new()),
+                false => groups.last_mut().unwrap().push(line),
+            }
+        }
+
+        let sections = groups
+            .into_iter()
+            .filter(|lines| !lines.is_empty())
+            .map(|lines| {
+                let offset = |text: &str| text.as_ptr() as usize - input.as_ptr() as usize;
+                let first = lines[0].text();
+                let last = lines[lines.len() - 1].text();
+                let text = &input[offset(first)..offset(last) + last.len()];
+                Section { text, lines }
+            })
+            .collect();
+        Sections { sections }
+    }
+
+    pub fn len(&self) -> usize {
+        self.sections.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sections.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&Section<'a>> {
+        self.sections.get(index)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Section<'a>> {
+        self.sections.iter()
+    }
+
+    /// The sections of an input made of exactly `N` of them.
+    pub fn exactly<const N: usize>(self) -> SolutionResult<[Section<'a>; N]> {
+        let found = self.len();
+        let message = format!("Expected {} section(s), found {}", N, found);
+        match self.sections.get(N) {
+            Some(extra) => Err(extra.error(message)),
+            None => self
+                .sections
+                .try_into()
+                .map_err(|_| SolutionError::new(message)),
+        }
+    }
+
+    /// Parses every section with `parser`, for inputs of repeated blocks.
+    pub fn map<T>(
+        &self,
+        parser: impl FnMut(&Section<'a>) -> SolutionResult<T>,
+    ) -> SolutionResult<Vec<T>> {
+        self.iter().map(parser).collect()
+    }
+
+    /// Parses an input of one section per parser in `parsers`, a tuple of functions taking a
+    /// `&Section`. Returns the tuple of their values.
+    pub fn parse<P: SectionParsers<'a>>(self, parsers: P) -> SolutionResult<P::Output> {
+        parsers.parse(self)
+    }
+}
+
+impl<'a> Section<'a> {
+    /// The section as it is in the input, without the final line break.
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn lines(&self) -> &[Line<'a>] {
+        &self.lines
+    }
+
+    /// Error pointing at the start of the section.
+    pub fn error(&self, message: impl Display) -> SolutionError {
+        self.lines[0].error(message)
+    }
+
+    /// Parses every line with `parser`.
+    pub fn parse_lines<T>(
+        &self,
+        parser: impl FnMut(&Line<'a>) -> SolutionResult<T>,
+    ) -> SolutionResult<Vec<T>> {
+        self.lines.iter().map(parser).collect()
+    }
+
+    /// The lines as `key: value` headers split at `separator`.
+    pub fn headers(&self, separator: &str) -> SolutionResult<Headers<'a>> {
+        Headers::parse(self.lines.iter().copied(), separator)
+    }
+}
+
+/// Tuples of section parsers `Sections::parse` accepts.
+pub trait SectionParsers<'a> {
+    type Output;
+
+    fn parse(self, sections: Sections<'a>) -> SolutionResult<Self::Output>;
+}
+
+macro_rules! impl_section_parsers {
+    ($($parser:ident $value:ident $section:ident $index:tt),+) => {
+        impl<'a, $($parser, $value),+> SectionParsers<'a> for ($($parser,)+)
+        where
+            $($parser: FnOnce(&Section<'a>) -> SolutionResult<$value>,)+
+        {
+            type Output = ($($value,)+);
+
+            fn parse(self, sections: Sections<'a>) -> SolutionResult<Self::Output> {
+                let [$($section),+] = sections.exactly()?;
+                Ok(($((self.$index)(&$section)?,)+))
+            }
+        }
+    };
+}
+
+impl_section_parsers!(P1 T1 s1 0);
+impl_section_parsers!(P1 T1 s1 0, P2 T2 s2 1);
+impl_section_parsers!(P1 T1 s1 0, P2 T2 s2 1, P3 T3 s3 2);
+impl_section_parsers!(P1 T1 s1 0, P2 T2 s2 1, P3 T3 s3 2, P4 T4 s4 3);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "\
+47|53
+97|13
+
+75,47,61
+
+
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+
+";
+
+    #[test]
+    fn test_sections() {
+        let sections = Sections::new(INPUT);
+        assert_eq!(sections.len(), 3);
+        let rules = sections.get(0).unwrap();
+        assert_eq!(rules.text(), "47|53\n97|13");
+        assert_eq!(rules.lines().len(), 2);
+        assert_eq!(rules.lines()[1].index(), 1);
+        assert_eq!(sections.get(2).unwrap().lines()[0].index(), 6);
+        assert!(Sections::new("\n \n").is_empty());
+        assert_eq!(Sections::new("\n\na\n").get(0).unwrap().text(), "a");
+
+        let buttons = Sections::new("A: 1\nB: 2\n\nA: 3\nB: 4\n")
+            .map(|section| section.headers(":")?.get("B")?.parse::<u32>())
+            .unwrap();
+        assert_eq!(buttons, vec![2, 4]);
+    }
+
+    #[test]
+    fn test_exactly() {
+        let [rules, updates, buttons] = Sections::new(INPUT).exactly().unwrap();
+        assert_eq!(rules.lines()[0].text(), "47|53");
+        assert_eq!(updates.text(), "75,47,61");
+        assert_eq!(buttons.lines().len(), 2);
+
+        let err = Sections::new(INPUT).exactly::<2>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 7, column 1: Expected 2 section(s), found 3"
+        );
+        let err = Sections::new(INPUT).exactly::<4>().unwrap_err();
+        assert_eq!(err.to_string(), "Expected 4 section(s), found 3");
+    }
+
+    #[test]
+    fn test_parse() {
+        fn rules(section: &Section) -> SolutionResult<Vec<(u32, u32)>> {
+            section.parse_lines(|line| {
+                let (before, after) = line.split_once("|")?;
+                Ok((before.parse()?, after.parse()?))
+            })
+        }
+        fn update(section: &Section) -> SolutionResult<Vec<u32>> {
+            section.lines()[0].list(",")
+        }
+        fn buttons(section: &Section) -> SolutionResult<usize> {
+            Ok(section.lines().len())
+        }
+
+        let parsed = Sections::new(INPUT).parse((rules, update, buttons));
+        assert_eq!(parsed, Ok((vec![(47, 53), (97, 13)], vec![75, 47, 61], 2)));
+
+        let err = Sections::new(INPUT)
+            .parse((update, rules, buttons))
+            .unwrap_err();
+        assert_eq!((err.line, err.column), (Some(1), Some(1)));
+    }
+}
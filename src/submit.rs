@@ -0,0 +1,491 @@
+use crate::client::{missing_session, HttpClient};
+use crate::PuzzleId;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::io;
+use std::path::Path;
+use std::sync::OnceLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Default location of the submission ledger, relative to the crate root.
+pub const LEDGER_FILE: &str = ".aoc-submissions.toml";
+
+/// Cooldown assumed after a wrong answer whose response doesn't say how long to wait.
+const WRONG_ANSWER_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// Judgement of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Verdict {
+    #[serde(rename = "correct")]
+    Correct,
+    #[serde(rename = "wrong")]
+    Wrong,
+    #[serde(rename = "too high")]
+    TooHigh,
+    #[serde(rename = "too low")]
+    TooLow,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "That's the right answer"),
+            Verdict::Wrong => write!(f, "That's not the right answer"),
+            Verdict::TooHigh => write!(f, "That's not the right answer, it is too high"),
+            Verdict::TooLow => write!(f, "That's not the right answer, it is too low"),
+        }
+    }
+}
+
+/// Reply of the site to a submission.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reply {
+    /// The answer was judged, `cooldown` is how long to wait before the next submission.
+    Judged {
+        verdict: Verdict,
+        cooldown: Option<Duration>,
+    },
+    /// An answer was submitted too recently, nothing was judged.
+    TooSoon(Duration),
+    /// The part was already solved, or isn't unlocked yet.
+    WrongLevel,
+    /// A response this parser doesn't know, with the text of its message.
+    Unrecognized(String),
+}
+
+impl Reply {
+    /// Parses the HTML page returned for a submission.
+    pub fn parse(body: &str) -> Self {
+        let text = message(body);
+        static MINUTES: OnceLock<Regex> = OnceLock::new();
+        static SECONDS: OnceLock<Regex> = OnceLock::new();
+        let minutes = |text: &str| {
+            MINUTES
+                .get_or_init(|| regex(r"wait (one|\d+) minutes?"))
+                .captures(text)
+                .map(|caps| match &caps[1] {
+                    "one" => 1,
+                    n => n.parse().unwrap_or(1),
+                })
+                .map(|minutes: u64| Duration::from_secs(minutes * 60))
+        };
+
+        if text.contains("That's the right answer") {
+            Reply::Judged {
+                verdict: Verdict::Correct,
+                cooldown: None,
+            }
+        } else if text.contains("That's not the right answer") {
+            let verdict = if text.contains("too high") {
+                Verdict::TooHigh
+            } else if text.contains("too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            };
+            Reply::Judged {
+                verdict,
+                cooldown: Some(minutes(&text).unwrap_or(WRONG_ANSWER_COOLDOWN)),
+            }
+        } else if text.contains("You gave an answer too recently") {
+            let wait = SECONDS
+                .get_or_init(|| regex(r"(?:(\d+)m )?(\d+)s left to wait"))
+                .captures(&text)
+                .map(|caps| {
+                    let minutes: u64 = caps.get(1).map_or(0, |m| m.as_str().parse().unwrap_or(0));
+                    let seconds: u64 = caps[2].parse().unwrap_or(0);
+                    Duration::from_secs(minutes * 60 + seconds)
+                })
+                .or_else(|| minutes(&text))
+                .unwrap_or(WRONG_ANSWER_COOLDOWN);
+            Reply::TooSoon(wait)
+        } else if text.contains("You don't seem to be solving the right level") {
+            Reply::WrongLevel
+        } else {
+            Reply::Unrecognized(text)
+        }
+    }
+}
+
+impl fmt::Display for Reply {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Reply::Judged {
+                verdict,
+                cooldown: None,
+            } => write!(f, "{}", verdict),
+            Reply::Judged {
+                verdict,
+                cooldown: Some(cooldown),
+            } => write!(f, "{}, next submission in {:?}", verdict, cooldown),
+            Reply::TooSoon(wait) => write!(f, "Answered too recently, wait {:?}", wait),
+            Reply::WrongLevel => write!(f, "Part already solved or not unlocked yet"),
+            Reply::Unrecognized(text) => write!(f, "Unrecognized response: {}", text),
+        }
+    }
+}
+
+/// Text of the `<article>` holding the message of a response page, without markup.
+fn message(body: &str) -> String {
+    let article = body
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .map(|(_, rest)| rest.split("</article>").next().unwrap_or(rest))
+        .unwrap_or(body);
+    static TAG: OnceLock<Regex> = OnceLock::new();
+    let text = TAG
+        .get_or_init(|| regex(r"<[^>]*>"))
+        .replace_all(article, "");
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn regex(pattern: &str) -> Regex {
+    Regex::new(pattern).expect("Response patterns are valid")
+}
+
+/// A judged submission.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub year: u32,
+    pub day: u32,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Unix time of the submission, in seconds.
+    pub at: u64,
+}
+
+/// Every judged submission and the end of the current cooldown, stored as TOML.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Ledger {
+    /// Unix time before which nothing may be submitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wait_until: Option<u64>,
+    #[serde(default, rename = "attempt")]
+    pub attempts: Vec<Attempt>,
+}
+
+/// Reasons a submission is refused before it is sent.
+#[derive(Debug)]
+pub enum SubmitError {
+    Cooldown(Duration),
+    AlreadySolved(String),
+    Duplicate(Verdict),
+    /// The answer lies beyond an earlier answer which was too high or too low.
+    OutOfRange {
+        verdict: Verdict,
+        bound: String,
+    },
+    Io(io::Error),
+}
+
+impl fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitError::Cooldown(wait) => {
+                write!(f, "Submitted too recently, wait another {:?}", wait)
+            }
+            SubmitError::AlreadySolved(answer) => {
+                write!(f, "Already solved with answer {}", answer)
+            }
+            SubmitError::Duplicate(verdict) => {
+                write!(f, "Already submitted, the answer was: {}", verdict)
+            }
+            SubmitError::OutOfRange { verdict, bound } => match verdict {
+                Verdict::TooLow => write!(f, "{} was already too low", bound),
+                _ => write!(f, "{} was already too high", bound),
+            },
+            SubmitError::Io(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+impl From<io::Error> for SubmitError {
+    fn from(err: io::Error) -> Self {
+        SubmitError::Io(err)
+    }
+}
+
+impl Ledger {
+    /// Reads the ledger at `path`, a missing file is an empty ledger.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => toml::from_str(&text)
+                .map_err(|err| io::Error::other(format!("{}: {}", path.display(), err))),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Ledger::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        std::fs::write(path, toml::to_string(self).map_err(io::Error::other)?)
+    }
+
+    pub fn attempts(&self, id: PuzzleId, part: u8) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |a| a.year == id.year && a.day == id.day && a.part == part)
+    }
+
+    /// Checks whether `answer` may be submitted at unix time `now`.
+    pub fn check(&self, id: PuzzleId, part: u8, answer: &str, now: u64) -> Result<(), SubmitError> {
+        if let Some(wait) = self.wait_until.filter(|&until| until > now) {
+            return Err(SubmitError::Cooldown(Duration::from_secs(wait - now)));
+        }
+
+        let number = answer.parse::<i128>().ok();
+        for attempt in self.attempts(id, part) {
+            if attempt.verdict == Verdict::Correct {
+                return Err(SubmitError::AlreadySolved(attempt.answer.clone()));
+            }
+            if attempt.answer == answer {
+                return Err(SubmitError::Duplicate(attempt.verdict));
+            }
+
+            let out_of_range = match (number, attempt.answer.parse::<i128>().ok()) {
+                (Some(number), Some(bound)) => match attempt.verdict {
+                    Verdict::TooHigh => number >= bound,
+                    Verdict::TooLow => number <= bound,
+                    _ => false,
+                },
+                _ => false,
+            };
+            if out_of_range {
+                return Err(SubmitError::OutOfRange {
+                    verdict: attempt.verdict,
+                    bound: attempt.answer.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Records `reply` to a submission of `answer` at unix time `now`.
+    pub fn record(&mut self, id: PuzzleId, part: u8, answer: &str, reply: &Reply, now: u64) {
+        let cooldown = match reply {
+            Reply::Judged { verdict, cooldown } => {
+                self.attempts.push(Attempt {
+                    year: id.year,
+                    day: id.day,
+                    part,
+                    answer: answer.to_string(),
+                    verdict: *verdict,
+                    at: now,
+                });
+                *cooldown
+            }
+            Reply::TooSoon(wait) => Some(*wait),
+            Reply::WrongLevel | Reply::Unrecognized(_) => None,
+        };
+        if let Some(cooldown) = cooldown {
+            self.wait_until = Some(now + cooldown.as_secs());
+        }
+    }
+}
+
+/// Current unix time in seconds.
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |time| time.as_secs())
+}
+
+/// Posts answers, guarded by a `Ledger`.
+pub struct Submitter {
+    client: Box<dyn HttpClient>,
+    base_url: String,
+    session: Option<String>,
+}
+
+impl Submitter {
+    pub fn new(
+        client: Box<dyn HttpClient>,
+        base_url: impl Into<String>,
+        session: Option<String>,
+    ) -> Self {
+        Submitter {
+            client,
+            base_url: base_url.into(),
+            session,
+        }
+    }
+
+    pub fn url(&self, id: PuzzleId) -> String {
+        format!("{}/{}/day/{}/answer", self.base_url, id.year, id.day)
+    }
+
+    /// Submits `answer` for `part` of `id` unless `ledger` refuses it, and records the reply.
+    /// The caller saves the ledger.
+    pub fn submit(
+        &self,
+        ledger: &mut Ledger,
+        id: PuzzleId,
+        part: u8,
+        answer: &str,
+        now: u64,
+    ) -> Result<Reply, SubmitError> {
+        ledger.check(id, part, answer, now)?;
+
+        let session = self.session.as_deref().ok_or_else(missing_session)?;
+        let url = self.url(id);
+        let response = self.client.post(
+            &url,
+            &[("Cookie", &format!("session={}", session))],
+            &[("level", &part.to_string()), ("answer", answer)],
+        )?;
+        if response.status != 200 {
+            return Err(SubmitError::Io(io::Error::other(format!(
+                "{} responded with status {}",
+                url, response.status
+            ))));
+        }
+
+        let reply = Reply::parse(&response.body);
+        ledger.record(id, part, answer, &reply, now);
+        Ok(reply)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::stub_server;
+    use crate::client::UreqClient;
+
+    fn page(message: &str) -> String {
+        format!(
+            "<html><main>\n<article><p>{}</p></article>\n</main></html>",
+            message
+        )
+    }
+
+    #[test]
+    fn test_parse_reply() {
+        let right = page("That's the right answer!  You are <span>one gold star</span> closer.");
+        assert_eq!(
+            Reply::parse(&right),
+            Reply::Judged {
+                verdict: Verdict::Correct,
+                cooldown: None
+            }
+        );
+
+        let high = page(
+            "That's not the right answer; your answer is too high.  Please wait one minute \
+             before trying again. <a href=\"/2024/day/5\">[Return to Day 5]</a>",
+        );
+        assert_eq!(
+            Reply::parse(&high),
+            Reply::Judged {
+                verdict: Verdict::TooHigh,
+                cooldown: Some(Duration::from_secs(60))
+            }
+        );
+
+        let wrong =
+            page("That's not the right answer.  Please wait 5 minutes before trying again.");
+        assert_eq!(
+            Reply::parse(&wrong),
+            Reply::Judged {
+                verdict: Verdict::Wrong,
+                cooldown: Some(Duration::from_secs(300))
+            }
+        );
+
+        let recent = page(
+            "You gave an answer too recently; you have to wait after submitting an answer \
+             before trying again.  You have 1m 5s left to wait.",
+        );
+        assert_eq!(
+            Reply::parse(&recent),
+            Reply::TooSoon(Duration::from_secs(65))
+        );
+
+        let level =
+            page("You don't seem to be solving the right level.  Did you already complete it?");
+        assert_eq!(Reply::parse(&level), Reply::WrongLevel);
+        assert_eq!(
+            Reply::parse(&page("Hm <em>?</em>")),
+            Reply::Unrecognized("Hm ?".to_string())
+        );
+    }
+
+    #[test]
+    fn test_ledger() {
+        let id = PuzzleId::day(5).unwrap();
+        let mut ledger = Ledger::default();
+        assert!(ledger.check(id, 1, "100", 0).is_ok());
+
+        let high = Reply::Judged {
+            verdict: Verdict::TooHigh,
+            cooldown: Some(Duration::from_secs(60)),
+        };
+        ledger.record(id, 1, "100", &high, 1000);
+        assert!(matches!(
+            ledger.check(id, 1, "50", 1030),
+            Err(SubmitError::Cooldown(wait)) if wait == Duration::from_secs(30)
+        ));
+        assert!(matches!(
+            ledger.check(id, 1, "100", 1060),
+            Err(SubmitError::Duplicate(Verdict::TooHigh))
+        ));
+        assert!(matches!(
+            ledger.check(id, 1, "120", 1060),
+            Err(SubmitError::OutOfRange { .. })
+        ));
+        assert!(ledger.check(id, 1, "50", 1060).is_ok());
+        assert!(ledger.check(id, 2, "100", 1060).is_ok());
+
+        let right = Reply::Judged {
+            verdict: Verdict::Correct,
+            cooldown: None,
+        };
+        ledger.record(id, 1, "50", &right, 1060);
+        assert!(matches!(
+            ledger.check(id, 1, "49", 2000),
+            Err(SubmitError::AlreadySolved(answer)) if answer == "50"
+        ));
+
+        let text = toml::to_string(&ledger).unwrap();
+        assert!(text.contains("verdict = \"too high\""));
+        assert_eq!(toml::from_str::<Ledger>(&text).unwrap(), ledger);
+    }
+
+    #[test]
+    fn test_submit_fake_server() {
+        let (url, server) = stub_server(
+            "200 OK",
+            &page("That's not the right answer; your answer is too low."),
+        );
+        let submitter = Submitter::new(
+            Box::new(UreqClient::default()),
+            url,
+            Some("token".to_string()),
+        );
+        let id = PuzzleId::day(3).unwrap();
+        let mut ledger = Ledger::default();
+
+        let reply = submitter.submit(&mut ledger, id, 2, "42", 100).unwrap();
+        assert!(matches!(
+            reply,
+            Reply::Judged {
+                verdict: Verdict::TooLow,
+                ..
+            }
+        ));
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2024/day/3/answer HTTP/1.1"));
+        assert!(request.contains("session=token"));
+        assert!(request.ends_with("level=2&answer=42"));
+
+        assert_eq!(ledger.wait_until, Some(160));
+        // Refused by the ledger, no request goes out
+        assert!(matches!(
+            submitter.submit(&mut ledger, id, 2, "42", 200),
+            Err(SubmitError::Duplicate(Verdict::TooLow))
+        ));
+    }
+}
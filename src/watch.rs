@@ -0,0 +1,267 @@
+use crate::report::{Record, Status};
+use crate::PuzzleId;
+use notify::{RecommendedWatcher, RecursiveMode, Watcher as _};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::{Duration, SystemTime};
+
+/// How often `Watcher::wait` looks for changes, and how long it waits for more of them.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Watches files below a set of paths.
+///
+/// Changes are picked up from the file system notifications of the OS. Where those can't
+/// be set up, e.g. for a path that doesn't exist yet or on file systems without them in
+/// containers, the watcher falls back to polling the modification times. Either way the
+/// changed files are found by comparing snapshots of the modification times and sizes.
+pub struct Watcher {
+    paths: Vec<PathBuf>,
+    snapshot: BTreeMap<PathBuf, (SystemTime, u64)>,
+    /// `None` while polling. The notifier only sends as long as it is kept alive.
+    notifications: Option<(RecommendedWatcher, Receiver<notify::Result<notify::Event>>)>,
+}
+
+impl Watcher {
+    /// Watches `paths` through notifications, or by polling if they can't be set up.
+    pub fn new(paths: Vec<PathBuf>) -> Self {
+        let mut watcher = Watcher::polling(paths);
+        watcher.notifications = notifications(&watcher.paths).ok();
+        watcher
+    }
+
+    /// Watches `paths` by polling only.
+    pub fn polling(paths: Vec<PathBuf>) -> Self {
+        let snapshot = snapshot(&paths);
+        Watcher {
+            paths,
+            snapshot,
+            notifications: None,
+        }
+    }
+
+    pub fn is_polling(&self) -> bool {
+        self.notifications.is_none()
+    }
+
+    /// Files added, modified or removed since the last call, or since the watcher was created.
+    pub fn changed(&mut self) -> Vec<PathBuf> {
+        let snapshot = snapshot(&self.paths);
+        let mut changed: Vec<PathBuf> = snapshot
+            .iter()
+            .filter(|(path, stamp)| self.snapshot.get(*path) != Some(stamp))
+            .map(|(path, _)| path.clone())
+            .collect();
+        changed.extend(
+            self.snapshot
+                .keys()
+                .filter(|path| !snapshot.contains_key(*path))
+                .cloned(),
+        );
+        self.snapshot = snapshot;
+        changed
+    }
+
+    /// Blocks until files change, then waits for one quiet interval so an editor writing
+    /// several files triggers a single run.
+    pub fn wait(&mut self, interval: Duration) -> Vec<PathBuf> {
+        let mut changed = Vec::new();
+        loop {
+            match self.notified(interval) {
+                // Nothing happened for a whole interval
+                Some(false) if changed.is_empty() => continue,
+                Some(false) => return changed,
+                Some(true) => {}
+                None => std::thread::sleep(interval),
+            }
+            let batch = self.changed();
+            if batch.is_empty() && !changed.is_empty() {
+                return changed;
+            }
+            changed.extend(batch);
+        }
+    }
+
+    /// Whether a notification arrived within `interval`, `None` while polling. A notifier
+    /// which stopped makes the watcher poll from then on.
+    fn notified(&mut self, interval: Duration) -> Option<bool> {
+        let (_, events) = self.notifications.as_ref()?;
+        match events.recv_timeout(interval) {
+            Ok(_) => {
+                // Collect the burst of events a single save causes
+                while events.try_recv().is_ok() {}
+                Some(true)
+            }
+            Err(RecvTimeoutError::Timeout) => Some(false),
+            Err(RecvTimeoutError::Disconnected) => {
+                self.notifications = None;
+                None
+            }
+        }
+    }
+}
+
+/// Notifications for everything below `paths`, all of which have to exist.
+fn notifications(
+    paths: &[PathBuf],
+) -> notify::Result<(RecommendedWatcher, Receiver<notify::Result<notify::Event>>)> {
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender)?;
+    for path in paths {
+        watcher.watch(path, RecursiveMode::Recursive)?;
+    }
+    Ok((watcher, receiver))
+}
+
+/// Modification time and size of every file below `paths`, missing paths are skipped.
+fn snapshot(paths: &[PathBuf]) -> BTreeMap<PathBuf, (SystemTime, u64)> {
+    let mut files = BTreeMap::new();
+    let mut pending: Vec<PathBuf> = paths.to_vec();
+    while let Some(path) = pending.pop() {
+        let Ok(meta) = std::fs::metadata(&path) else {
+            continue;
+        };
+        if meta.is_dir() {
+            if let Ok(entries) = std::fs::read_dir(&path) {
+                pending.extend(entries.flatten().map(|entry| entry.path()));
+            }
+        } else {
+            let modified = meta.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+            files.insert(path, (modified, meta.len()));
+        }
+    }
+    files
+}
+
+/// Filter selecting the generated fixture tests of `id`, see `examples`.
+pub fn fixture_filter(id: PuzzleId) -> String {
+    if id.year == crate::puzzle::YEAR {
+        format!("examples::tests::day{:0>2}_", id.day)
+    } else {
+        format!("examples::tests::y{}_day{:0>2}_", id.year, id.day)
+    }
+}
+
+/// Lines of a run compared against the previous one: the answer of every part, marked
+/// when it differs from the last run.
+pub fn diff(previous: &[Record], current: &[Record]) -> Vec<String> {
+    current
+        .iter()
+        .map(|record| {
+            let outcome = |record: &Record| match record.status {
+                Status::Solved => record.answer.to_string(),
+                status => match &record.error {
+                    Some(error) => format!("{} ({})", status, error),
+                    None => status.to_string(),
+                },
+            };
+            let now = outcome(record);
+            let before = previous
+                .iter()
+                .find(|r| (r.year, r.day, r.part) == (record.year, record.day, record.part));
+            let change = match before.map(outcome) {
+                None => String::new(),
+                Some(before) if before == now => String::new(),
+                Some(before) => format!("   (changed, was {})", before),
+            };
+            format!(
+                "Day {:0>2} - Part {}: {}{}",
+                record.day, record.part, now, change
+            )
+        })
+        .collect()
+}
+
+/// Directories watched for a run on `input_dir`: the solutions, the bins and the inputs.
+pub fn watched_paths(crate_root: &Path, input_dir: &Path) -> Vec<PathBuf> {
+    vec![
+        crate_root.join("src/days"),
+        crate_root.join("src/bin"),
+        input_dir.to_path_buf(),
+        crate_root.join(crate::examples::FIXTURES_DIR),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Answer;
+
+    #[test]
+    fn test_watcher() {
+        let dir = std::env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("nested")).unwrap();
+        std::fs::write(dir.join("nested/01.txt"), "1").unwrap();
+
+        let mut watcher = Watcher::new(vec![dir.clone(), dir.join("missing")]);
+        assert!(watcher.changed().is_empty());
+
+        std::fs::write(dir.join("nested/01.txt"), "12").unwrap();
+        std::fs::write(dir.join("02.txt"), "2").unwrap();
+        let mut changed = watcher.changed();
+        changed.sort();
+        assert_eq!(changed, vec![dir.join("02.txt"), dir.join("nested/01.txt")]);
+
+        std::fs::remove_file(dir.join("02.txt")).unwrap();
+        assert_eq!(
+            watcher.wait(Duration::from_millis(1)),
+            vec![dir.join("02.txt")]
+        );
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_notifications() {
+        let dir = std::env::temp_dir().join(format!("aoc-notify-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("nested")).unwrap();
+
+        // A missing path can't be watched through notifications
+        assert!(Watcher::new(vec![dir.clone(), dir.join("missing")]).is_polling());
+
+        let mut watcher = Watcher::new(vec![dir.clone()]);
+        if watcher.is_polling() {
+            // No notifications on this system, polling is covered by `test_watcher`
+            std::fs::remove_dir_all(dir).unwrap();
+            return;
+        }
+        let writer = {
+            let dir = dir.clone();
+            std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(50));
+                std::fs::write(dir.join("nested/01.txt"), "1").unwrap();
+            })
+        };
+        assert_eq!(
+            watcher.wait(Duration::from_millis(100)),
+            vec![dir.join("nested/01.txt")]
+        );
+        writer.join().unwrap();
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_diff() {
+        let record = |part, answer: u64| Record {
+            year: 2024,
+            day: 5,
+            part,
+            status: Status::Solved,
+            answer: Answer::from(answer),
+            error: None,
+            duration_ns: 0,
+            input_hash: String::new(),
+        };
+        let previous = vec![record(1, 143), record(2, 120)];
+        let current = vec![record(1, 143), record(2, 123)];
+        assert_eq!(
+            diff(&previous, &current),
+            vec![
+                "Day 05 - Part 1: 143",
+                "Day 05 - Part 2: 123   (changed, was 120)"
+            ]
+        );
+        assert_eq!(diff(&[], &current[..1]), vec!["Day 05 - Part 1: 143"]);
+    }
+}